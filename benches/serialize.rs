@@ -0,0 +1,45 @@
+//! Compares the sequential and the `to_json()` serialization of a large snippets file.
+//! 
+//! Run with `cargo bench --bench serialize --features rayon` to measure the parallel path.
+
+use std::time::Instant;
+use vscode_generator::{ Snippet, SnippetsFile };
+
+const SNIPPETS: usize = 50_000;
+const RUNS: u32 = 10;
+
+fn main() {
+    let snippets = SnippetsFile::new((0..SNIPPETS).map(|n| {
+        Snippet::builder()
+            .set_prefix(format!("snippet{n}"))
+            .set_body(vec![
+                format!("fn function_{n}(${{1:args}}) -> ${{2:Result<()>}} {{"),
+                "    ${0:// TODO: ...}".to_owned(),
+                "}".to_owned(),
+            ])
+            .set_description(format!("Generated function #{n}"))
+            .set_scope("rust")
+    }));
+
+    // warm up (allocator, thread pool):
+    let sequential = snippets.to_json_with(2).unwrap();
+    let json = snippets.to_json().unwrap();
+
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        snippets.to_json_with(2).unwrap();
+    }
+    let sequential_time = start.elapsed() / RUNS;
+
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        snippets.to_json().unwrap();
+    }
+    let time = start.elapsed() / RUNS;
+
+    assert_eq!(json, sequential);
+
+    println!("{SNIPPETS} snippets ({} bytes):", json.len());
+    println!("  sequential: {sequential_time:?}");
+    println!("  to_json:    {time:?} (rayon: {})", cfg!(feature = "rayon"));
+}
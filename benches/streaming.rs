@@ -0,0 +1,66 @@
+//! Compares the peak memory of the `to_json()` and the streamed serialization of a large snippets set.
+//! 
+//! Run with `cargo bench --bench streaming`.
+
+use std::{ io, alloc::{ GlobalAlloc, Layout, System }, sync::atomic::{ AtomicUsize, Ordering } };
+use vscode_generator::{ Snippet, SnippetsFile };
+
+const SNIPPETS: usize = 50_000;
+
+/// The system allocator counting the current and the peak allocated bytes
+struct Counter;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counter = Counter;
+
+fn snippet(n: usize) -> Snippet {
+    Snippet::builder()
+        .set_prefix(format!("snippet{n}"))
+        .set_body(vec![
+            format!("fn function_{n}(${{1:args}}) -> ${{2:Result<()>}} {{"),
+            "    ${0:// TODO: ...}".to_owned(),
+            "}".to_owned(),
+        ])
+        .set_description(format!("Generated function #{n}"))
+        .set_scope("rust")
+        .build()
+        .unwrap()
+}
+
+/// Returns the peak bytes allocated by the closure (above the bytes allocated before it)
+fn peak<T>(f: impl FnOnce() -> T) -> usize {
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    drop(f());
+    PEAK.load(Ordering::Relaxed) - base
+}
+
+fn main() {
+    let file = SnippetsFile::new((0..SNIPPETS).map(snippet).collect::<Vec<_>>());
+
+    let to_json = peak(|| file.to_json().unwrap());
+    let write_to_writer = peak(|| file.write_to_writer(io::sink()).unwrap());
+    drop(file);
+    let from_iter_streaming = peak(|| SnippetsFile::from_iter_streaming(io::sink(), (0..SNIPPETS).map(snippet)).unwrap());
+
+    println!("{SNIPPETS} snippets, peak memory:");
+    println!("  to_json:             {:>6} KiB (the snippets are built beforehand)", to_json / 1024);
+    println!("  write_to_writer:     {:>6} KiB (the snippets are built beforehand)", write_to_writer / 1024);
+    println!("  from_iter_streaming: {:>6} KiB (including the snippets)", from_iter_streaming / 1024);
+}
@@ -9,7 +9,21 @@ pub enum Error {
     NameIsRequired,
     PrefixIsRequired,
     BodyIsEmpty,
-    IndexOutOfBounds(usize)
+    DescriptionIsRequired,
+    FileTemplateIndented,
+    IndexOutOfBounds(usize),
+    InvalidSnippetSyntax { line: usize, column: usize, message: String },
+    UnknownLanguage(String),
+    Parse { offset: usize, message: String },
+    DuplicateName(String),
+    NameNotFound(String),
+    SnippetBuild { prefix: String, source: Box<Error> },
+    UserDirNotFound(String),
+    SchemaViolation(String),
+    PathIsDirectory(std::path::PathBuf),
+    FileKindMismatch(std::path::PathBuf),
+    MultiScopeInLanguageFile(String),
+    SnippetSerialize { name: String, source: serde_json::Error },
 }
 
 impl std::fmt::Debug for Error {
@@ -20,7 +34,21 @@ impl std::fmt::Debug for Error {
             Self::NameIsRequired => write!(f, "Name is required"),
             Self::PrefixIsRequired => write!(f, "Prefix is required"),
             Self::BodyIsEmpty => write!(f, "Body cannot be empty"),
-            Self::IndexOutOfBounds(n) => write!(f, "Index '{n}' out of bounds")
+            Self::DescriptionIsRequired => write!(f, "Description is required for file templates"),
+            Self::FileTemplateIndented => write!(f, "File template body must start at column zero"),
+            Self::IndexOutOfBounds(n) => write!(f, "Index '{n}' out of bounds"),
+            Self::InvalidSnippetSyntax { line, column, message } => write!(f, "Invalid snippet syntax at {line}:{column}: {message}"),
+            Self::UnknownLanguage(id) => write!(f, "Unknown language id '{id}'"),
+            Self::Parse { offset, message } => write!(f, "Parse error at byte {offset}: {message}"),
+            Self::DuplicateName(name) => write!(f, "Snippet name '{name}' is already taken"),
+            Self::NameNotFound(name) => write!(f, "Snippet name '{name}' is not found"),
+            Self::SnippetBuild { prefix, source } => write!(f, "Failed to build snippet '{prefix}': {source:?}"),
+            Self::UserDirNotFound(var) => write!(f, "Cannot locate the user folder: the '{var}' environment variable is not set"),
+            Self::SchemaViolation(pointer) => write!(f, "The snippets don't match the schema at '{pointer}'"),
+            Self::PathIsDirectory(path) => write!(f, "The path '{}' is a directory, expected a file path", path.display()),
+            Self::FileKindMismatch(path) => write!(f, "The extension of '{}' doesn't match the snippets file kind", path.display()),
+            Self::MultiScopeInLanguageFile(name) => write!(f, "Snippet '{name}' has multiple scopes, they only work in global '.code-snippets' files"),
+            Self::SnippetSerialize { name, source } => write!(f, "Failed to serialize snippet '{name}': {source}"),
         }
     }
 }
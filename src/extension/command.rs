@@ -0,0 +1,32 @@
+use serde::Serialize;
+
+/// A `contributes.commands` entry of the extension manifest (shown in the Command Palette)
+///
+/// The manifest only declares the command, its handler is registered by the extension code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct Command {
+    /// The command id (e.g. `"mySnippets.insert"`)
+    pub command: String,
+    /// The human-readable title
+    pub title: String,
+    /// The optional category (the palette shows `Category: Title`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+}
+
+impl Command {
+    /// Creates a new command
+    pub fn new<S: Into<String>>(command: S, title: S) -> Self {
+        Self {
+            command: command.into(),
+            title: title.into(),
+            category: None,
+        }
+    }
+
+    /// Sets the command category
+    pub fn set_category<S: Into<String>>(mut self, category: S) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+}
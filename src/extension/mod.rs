@@ -0,0 +1,45 @@
+//! # The Extension Module
+//! 
+//! 📦 This module assembles a whole VS Code extension: the snippets, commands, keybindings
+//! and settings are collected into one [`Extension`] and written as a complete `package.json`
+//! with all the referenced files.
+//! 
+//! ## Components
+//! 
+//! - [`Extension`] - The extension manifest and files generator
+//! - [`Command`] - The Command Palette command
+//! - [`Setting`] - The configuration property
+//! - [`Keybinding`](crate::keybindings::Keybinding) - The key chord running a command
+//! 
+//! ## Examples
+//! 
+//! ```rust,no_run
+//! use vscode_generator::{ Snippet, SnippetsFile, extension::{ Extension, Command }, keybindings::{ Keybinding, When } };
+//! # #[cfg(feature = "fs")]
+//! # fn main() -> vscode_generator::Result<()> {
+//! 
+//! Extension::new("rust-snippets", "SynapDrake")
+//!     .add_snippets("rust", SnippetsFile::new(vec![
+//!         Snippet::new("fn", vec!["fn $1() {", "    $0", "}"])
+//!     ]))
+//!     .add_command(Command::new("rustSnippets.refresh", "Refresh Snippets"))
+//!     .add_keybinding(Keybinding::new("ctrl+alt+r", "rustSnippets.refresh").set_when(When::EditorTextFocus))
+//!     .write_to_dir("./rust-snippets")?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "fs"))] fn main() {}
+//! ```
+//! 
+//! ## Output
+//! 
+//! ```text
+//! rust-snippets/
+//! ├── package.json
+//! ├── .vscodeignore
+//! └── snippets/
+//!     └── rust.code-snippets
+//! ```
+
+pub mod vscode_extension;    pub use vscode_extension::Extension;
+pub mod command;             pub use command::Command;
+pub mod setting;             pub use setting::Setting;
@@ -0,0 +1,41 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// A `contributes.configuration` property of the extension manifest (shown in the Settings editor)
+///
+/// The setting type is inferred from the default value (`true` → `"boolean"`, `4` → `"number"`, ...).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Setting {
+    /// The setting key (e.g. `"mySnippets.enabled"`), it's the property name in the manifest
+    #[serde(skip)]
+    pub key: String,
+    /// The JSON type of the value
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    /// The default value
+    pub default: Value,
+    /// The description shown in the Settings editor
+    pub description: String,
+}
+
+impl Setting {
+    /// Creates a new setting with the default value
+    pub fn new<S: Into<String>>(key: S, default: impl Into<Value>, description: S) -> Self {
+        let default = default.into();
+        let kind = match default {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        };
+
+        Self {
+            key: key.into(),
+            kind,
+            default,
+            description: description.into(),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::prelude::*;
+use crate::{ keybindings::Keybinding, scaffold::ExtensionScaffold, snippets::MergePolicy };
+use super::{ Command, Setting };
+#[cfg(feature = "fs")]
+use std::path::Path;
+use serde_json::{ json, Value };
+
+/// # The Extension
+/// 
+/// 📦 The aggregate of a whole VS Code extension: the snippets files, commands, keybindings
+/// and settings. It extends the [`ExtensionScaffold`] manifest, so the `package.json` has the
+/// `contributes` section wired to the written files (e.g. the snippets paths in `contributes.snippets`).
+/// 
+/// ## Usage
+/// 
+/// ```rust
+/// # use vscode_generator::{ Snippet, SnippetsFile, extension::{ Extension, Command, Setting }, keybindings::Keybinding };
+/// let extension = Extension::new("my-snippets", "me")
+///     .set_version("1.2.0")
+///     .add_snippets("rust", SnippetsFile::new(vec![Snippet::new("main", vec!["fn main() {", "    $0", "}"])]))
+///     .add_command(Command::new("mySnippets.insert", "Insert Snippet").set_category("My Snippets"))
+///     .add_keybinding(Keybinding::new("ctrl+alt+i", "mySnippets.insert"))
+///     .add_setting(Setting::new("mySnippets.enabled", true, "Enables the snippets"));
+/// 
+/// let manifest: serde_json::Value = serde_json::from_str(&extension.to_package_json().unwrap()).unwrap();
+/// let contributes = &manifest["contributes"];
+/// 
+/// assert_eq!(contributes["snippets"][0]["path"], "./snippets/rust.code-snippets");
+/// assert_eq!(contributes["keybindings"][0]["command"], "mySnippets.insert");
+/// assert_eq!(contributes["configuration"]["properties"]["mySnippets.enabled"]["type"], "boolean");
+/// ```
+/// 
+/// ## Methods
+/// 
+/// - `new(name, publisher)` - Creates the extension (version `0.0.1`)
+/// - `Extension::from(scaffold)` - Extends an existing scaffold
+/// - `set_version(version)`, `set_display_name(name)` - Sets the manifest metadata
+/// - `add_snippets(language, file)` - Adds the language snippets file
+/// - `add_command(command)`, `add_keybinding(keybinding)`, `add_setting(setting)` - Adds the contributions
+/// - `to_package_json()` - Generates the manifest
+/// - `write_to_dir(path)` - Writes the manifest and the referenced files
+#[derive(Debug, Clone)]
+pub struct Extension {
+    /// The manifest metadata and the snippets files
+    pub scaffold: ExtensionScaffold,
+    /// The name shown in the Marketplace (the `name` is used if not set)
+    pub display_name: Option<String>,
+    /// The Command Palette commands
+    pub commands: Vec<Command>,
+    /// The keybindings of the commands
+    pub keybindings: Vec<Keybinding>,
+    /// The configuration properties
+    pub settings: Vec<Setting>,
+}
+
+impl Extension {
+    /// Creates a new extension (with the `0.0.1` version)
+    pub fn new<S: Into<String>>(name: S, publisher: S) -> Self {
+        ExtensionScaffold::new(name.into(), publisher.into(), "0.0.1".to_owned()).into()
+    }
+
+    /// Sets the extension version (semver)
+    pub fn set_version<S: Into<String>>(mut self, version: S) -> Self {
+        self.scaffold.version = version.into();
+        self
+    }
+
+    /// Sets the name shown in the Marketplace
+    pub fn set_display_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.display_name = Some(name.into());
+        self
+    }
+
+    /// Adds a snippets file for the language
+    /// 
+    /// The files of the same language are merged into one (the taken names get a suffix, see `MergePolicy::KeepBoth`).
+    pub fn add_snippets<S: Into<String>>(mut self, language: S, snippets: SnippetsFile) -> Self {
+        let language = language.into();
+        match self.scaffold.snippets.iter_mut().find(|(lang, _)| *lang == language) {
+            Some((_, file)) => file.merge(snippets, MergePolicy::KeepBoth),
+            None => self.scaffold = self.scaffold.add_snippets(language, snippets),
+        }
+        self
+    }
+
+    /// Adds a Command Palette command
+    pub fn add_command(mut self, command: Command) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Adds a keybinding
+    pub fn add_keybinding(mut self, keybinding: Keybinding) -> Self {
+        self.keybindings.push(keybinding);
+        self
+    }
+
+    /// Adds a configuration property
+    pub fn add_setting(mut self, setting: Setting) -> Self {
+        self.settings.push(setting);
+        self
+    }
+
+    /// Returns the scaffold manifest extended with the other contributions (the empty ones are omitted)
+    fn manifest(&self) -> Result<Value> {
+        let mut manifest = self.scaffold.manifest();
+        let display_name = self.display_name.as_ref().unwrap_or(&self.scaffold.name);
+        manifest["displayName"] = json!(display_name);
+
+        if self.scaffold.snippets.is_empty() {
+            manifest["categories"] = json!(["Other"]);
+            manifest["contributes"].as_object_mut().unwrap().remove("snippets");
+        }
+        if !self.commands.is_empty() {
+            manifest["contributes"]["commands"] = serde_json::to_value(&self.commands)?;
+        }
+        if !self.keybindings.is_empty() {
+            manifest["contributes"]["keybindings"] = serde_json::to_value(&self.keybindings)?;
+        }
+        if !self.settings.is_empty() {
+            let mut properties = serde_json::Map::new();
+            for setting in &self.settings {
+                properties.insert(setting.key.clone(), serde_json::to_value(setting)?);
+            }
+            manifest["contributes"]["configuration"] = json!({
+                "title": display_name,
+                "properties": properties,
+            });
+        }
+
+        Ok(manifest)
+    }
+
+    /// Converts the extension manifest to json string
+    pub fn to_package_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(&self.manifest()?).map_err(Error::from)
+    }
+
+    /// `[fs]`: Writes the manifest, the `.vscodeignore` and the snippets files to the directory
+    #[cfg(feature = "fs")]
+    pub fn write_to_dir<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.scaffold.write_with_manifest(path.as_ref(), &self.to_package_json()?)
+    }
+}
+
+/// Extends the scaffold with the other contributions
+impl From<ExtensionScaffold> for Extension {
+    fn from(scaffold: ExtensionScaffold) -> Self {
+        Self {
+            scaffold,
+            display_name: None,
+            commands: vec![],
+            keybindings: vec![],
+            settings: vec![],
+        }
+    }
+}
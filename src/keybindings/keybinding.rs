@@ -0,0 +1,53 @@
+use serde::Serialize;
+
+/// # The Keybinding
+///
+/// ⌨️ A `contributes.keybindings` entry of the extension manifest: the key chord running
+/// the command, optionally restricted by a typed [`When`](super::When) clause.
+///
+/// ## Usage
+/// ```rust
+/// # use vscode_generator::keybindings::{ Keybinding, When };
+/// let binding = Keybinding::new("ctrl+alt+s", "mySnippets.insert")
+///     .set_mac("cmd+alt+s")
+///     .set_when(When::EditorTextFocus.and(!When::EditorReadonly));
+///
+/// assert_eq!(binding.when.as_deref(), Some("editorTextFocus && !editorReadonly"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct Keybinding {
+    /// The key chord (e.g. `"ctrl+shift+p"`)
+    pub key: String,
+    /// The command id
+    pub command: String,
+    /// The macOS key chord (the `key` is used if not set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mac: Option<String>,
+    /// The rendered `when` clause
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub when: Option<String>,
+}
+
+impl Keybinding {
+    /// Creates a new keybinding of the command
+    pub fn new<S: Into<String>>(key: S, command: S) -> Self {
+        Self {
+            key: key.into(),
+            command: command.into(),
+            mac: None,
+            when: None,
+        }
+    }
+
+    /// Sets the macOS key chord
+    pub fn set_mac<S: Into<String>>(mut self, key: S) -> Self {
+        self.mac = Some(key.into());
+        self
+    }
+
+    /// Sets the `when` clause (a typed [`When`](super::When) or a raw string)
+    pub fn set_when<S: Into<String>>(mut self, when: S) -> Self {
+        self.when = Some(when.into());
+        self
+    }
+}
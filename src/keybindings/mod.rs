@@ -0,0 +1,27 @@
+//! # The Keybindings Module
+//! 
+//! ⌨️ This module provides the typed building blocks for VS Code keybindings.
+//! 
+//! ## Components
+//! 
+//! - [`When`] - The `when` clause expression builder
+//! - [`Keybinding`] - The key chord running a command
+//! 
+//! ## Examples
+//! 
+//! ```rust
+//! use vscode_generator::keybindings::When;
+//! 
+//! let when = When::EditorTextFocus
+//!     .and(!When::EditorReadonly)
+//!     .and(When::EditorLangId("rust".to_owned()));
+//! 
+//! assert_eq!(when.to_string(), "editorTextFocus && !editorReadonly && editorLangId == rust");
+//! ```
+//! 
+//! #### See Also
+//! 
+//! - 🔗 VS Code [When clause contexts](https://code.visualstudio.com/api/references/when-clause-contexts)
+
+pub mod when;          pub use when::When;
+pub mod keybinding;    pub use keybinding::Keybinding;
@@ -0,0 +1,130 @@
+/// # The When Clause
+///
+/// 🚦 A typed `when` clause expression of a keybinding. A typo in a stringly-typed context
+/// silently disables the binding, so the common contexts are enum variants here.
+///
+/// ## Usage
+/// ```rust
+/// # use vscode_generator::keybindings::When;
+/// let when = When::EditorTextFocus.and(When::EditorLangId("rust".to_owned()).or(When::EditorLangId("toml".to_owned())));
+/// assert_eq!(when.to_string(), "editorTextFocus && (editorLangId == rust || editorLangId == toml)");
+///
+/// let not_readonly = !When::EditorReadonly;
+/// assert_eq!(not_readonly.to_string(), "!editorReadonly");
+/// ```
+///
+/// ## Rendering
+///
+/// - `&&` binds tighter than `||`, so the nested `or` clauses are wrapped in parentheses
+/// - The negated language check is rendered as `editorLangId != id`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum When {
+    /// The editor has the text focus (`editorTextFocus`)
+    EditorTextFocus,
+    /// The editor is read-only (`editorReadonly`)
+    EditorReadonly,
+    /// The editor has a selection (`editorHasSelection`)
+    EditorHasSelection,
+    /// The text input is focused (`inputFocus`)
+    InputFocus,
+    /// The editor language is the id (`editorLangId == id`)
+    EditorLangId(String),
+    /// Any other context key (e.g. `"suggestWidgetVisible"`)
+    Context(String),
+    /// The negated clause (`!clause`)
+    Not(Box<When>),
+    /// All the clauses are true (`a && b`)
+    And(Vec<When>),
+    /// Any of the clauses is true (`a || b`)
+    Or(Vec<When>),
+}
+
+impl When {
+    /// Combines the clauses with `&&`
+    pub fn and(self, other: When) -> When {
+        match self {
+            Self::And(mut clauses) => {
+                clauses.push(other);
+                Self::And(clauses)
+            }
+            this => Self::And(vec![this, other]),
+        }
+    }
+
+    /// Combines the clauses with `||`
+    pub fn or(self, other: When) -> When {
+        match self {
+            Self::Or(mut clauses) => {
+                clauses.push(other);
+                Self::Or(clauses)
+            }
+            this => Self::Or(vec![this, other]),
+        }
+    }
+
+    /// Writes the clause, wrapping it in parentheses if it's a compound one
+    fn fmt_operand(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::And(_) | Self::Or(_) => write!(f, "({self})"),
+            _ => write!(f, "{self}"),
+        }
+    }
+}
+
+impl std::ops::Not for When {
+    type Output = When;
+
+    fn not(self) -> When {
+        match self {
+            Self::Not(clause) => *clause,
+            clause => Self::Not(Box::new(clause)),
+        }
+    }
+}
+
+impl std::fmt::Display for When {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EditorTextFocus => write!(f, "editorTextFocus"),
+            Self::EditorReadonly => write!(f, "editorReadonly"),
+            Self::EditorHasSelection => write!(f, "editorHasSelection"),
+            Self::InputFocus => write!(f, "inputFocus"),
+            Self::EditorLangId(id) => write!(f, "editorLangId == {id}"),
+            Self::Context(key) => write!(f, "{key}"),
+            Self::Not(clause) => match clause.as_ref() {
+                Self::EditorLangId(id) => write!(f, "editorLangId != {id}"),
+                clause => {
+                    write!(f, "!")?;
+                    clause.fmt_operand(f)
+                }
+            },
+            Self::And(clauses) => {
+                for (i, clause) in clauses.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " && ")?;
+                    }
+                    match clause {
+                        Self::Or(_) => clause.fmt_operand(f)?,
+                        _ => write!(f, "{clause}")?,
+                    }
+                }
+                Ok(())
+            }
+            Self::Or(clauses) => {
+                for (i, clause) in clauses.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " || ")?;
+                    }
+                    write!(f, "{clause}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl From<When> for String {
+    fn from(value: When) -> Self {
+        value.to_string()
+    }
+}
@@ -8,6 +8,10 @@
 //! 
 //! ## Overview
 //! - ✨ Snippets generation with builder pattern
+//! - 🏗️ Extension scaffold generation (`package.json`, snippets, `.vscodeignore`)
+//! - ⌨️ Typed keybinding `when` clauses
+//! - 🧩 Workspace extension recommendations (`.vscode/extensions.json`)
+//! - 📦 Whole extension assembly (snippets, commands, keybindings and settings in one `package.json`)
 //! - 🎯 Future support for other VS Code extensions (planned)
 //! - 🛠 Rich customization options
 //! - ⚡ Efficient and type-safe implementation
@@ -19,8 +23,10 @@
 //! vscode-generator = "<latest-version>"
 //! ```
 //! #### Generating Snippets
-//! ```rust
+//! ```rust,no_run
 //! use vscode_generator::{ Snippet, SnippetsFile };
+//! # #[cfg(feature = "fs")]
+//! # fn main() -> vscode_generator::Result<()> {
 //! 
 //! // create snippets:
 //! let snippet = Snippet::builder()
@@ -37,6 +43,9 @@
 //! // save snippets to file:
 //! let snippets_file = SnippetsFile::new(vec![snippet]);
 //! snippets_file.write_to("./snippets/rust.code-snippets")?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "fs"))] fn main() {}
 //! ```
 //! ## User Snippets
 //! 📝 You can write this snippets to your VS Code custom user snippets folder
@@ -45,6 +54,8 @@
 //! * 🍎 MacOS: `~/Library/Application Support/Code/User/snippets`
 //! * 🐧 Linux: `~/.config/Code/User/snippets`
 //!
+//! 🧭 Use `snippets::user_snippets_dir()` to get it for the current OS and VS Code variant (Insiders, VSCodium, Cursor).
+//!
 //! 💡 Or simply access it via VS Code:
 //! 1. Press `Ctrl/Cmd + Shift + P`
 //! 2. Type "Snippets: Configure User Snippets"
@@ -62,3 +73,7 @@ pub mod error;      pub use error::{ Result, Error };
 pub mod prelude;
 
 pub mod snippets;   pub use snippets::{ Snippet, SnippetBuilder, SnippetsFile };
+pub mod scaffold;   pub use scaffold::ExtensionScaffold;
+pub mod keybindings;
+pub mod recommendations;
+pub mod extension;  pub use extension::Extension;
@@ -1,3 +1,26 @@
-pub use crate::error::*;
+//! # The Prelude
+//! 
+//! 📦 Re-exports the most used types, so a single import is enough:
+//! 
+//! ```rust
+//! use vscode_generator::prelude::*;
+//! 
+//! fn generate() -> Result<SnippetsFile> {
+//!     let snippet = Snippet::builder()
+//!         .set_prefix("fn")
+//!         .add_line("fn $1() {")
+//!         .add_line("    $0")
+//!         .add_line("}")
+//!         .build()?;
+//! 
+//!     Ok(SnippetsFile::new(vec![snippet]))
+//! }
+//! ```
+//! 
+//! ## Contents
+//! 
+//! - [`Snippet`], [`SnippetBuilder`], [`SnippetsFile`] - The snippets types
+//! - [`Result`], [`Error`] - The crate result and error types
 
-pub(crate) use std::collections::HashMap;
+pub use crate::error::{ Result, Error };
+pub use crate::snippets::{ Snippet, SnippetBuilder, SnippetsFile };
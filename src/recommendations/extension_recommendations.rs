@@ -0,0 +1,81 @@
+use crate::prelude::*;
+#[cfg(feature = "fs")]
+use std::{ fs, path::Path };
+use serde::Serialize;
+
+/// # The Extension Recommendations
+/// 
+/// 🧩 The workspace extension recommendations, VS Code reads them from `.vscode/extensions.json`
+/// and offers to install the recommended extensions (the unwanted ones are never suggested).
+/// 
+/// ## Usage
+/// 
+/// ```rust
+/// # use vscode_generator::recommendations::Recommendations;
+/// let json = Recommendations::new()
+///     .add("rust-lang.rust-analyzer")
+///     .add_unwanted("rust-lang.rust")
+///     .to_json()
+///     .unwrap();
+/// 
+/// assert_eq!(json, r#"{
+///   "recommendations": [
+///     "rust-lang.rust-analyzer"
+///   ],
+///   "unwantedRecommendations": [
+///     "rust-lang.rust"
+///   ]
+/// }"#);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Recommendations {
+    /// The recommended extension ids (`publisher.name`)
+    pub recommendations: Vec<String>,
+    /// The extension ids that shouldn't be recommended
+    pub unwanted_recommendations: Vec<String>,
+}
+
+impl Recommendations {
+    /// Creates an empty recommendations list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a recommended extension id (the duplicates are ignored)
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<S: Into<String>>(mut self, id: S) -> Self {
+        let id = id.into();
+        if !self.recommendations.contains(&id) {
+            self.recommendations.push(id);
+        }
+        self
+    }
+
+    /// Adds an unwanted extension id (the duplicates are ignored)
+    pub fn add_unwanted<S: Into<String>>(mut self, id: S) -> Self {
+        let id = id.into();
+        if !self.unwanted_recommendations.contains(&id) {
+            self.unwanted_recommendations.push(id);
+        }
+        self
+    }
+
+    /// Converts the recommendations to json string
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(Error::from)
+    }
+
+    /// `[fs]`: Writes the recommendations to file path (usually `.vscode/extensions.json`)
+    #[cfg(feature = "fs")]
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+
+        // creating the file dir:
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(Error::from)?;
+        }
+
+        fs::write(path, self.to_json()?).map_err(Error::from)
+    }
+}
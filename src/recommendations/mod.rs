@@ -0,0 +1,27 @@
+//! # The Recommendations Module
+//! 
+//! 🧩 This module generates the workspace extension recommendations (`.vscode/extensions.json`),
+//! so a scaffolded workspace prompts its users to install the right extensions.
+//! 
+//! ## Components
+//! 
+//! - [`Recommendations`] - The `extensions.json` generator
+//! 
+//! ## Examples
+//! 
+//! ```rust,no_run
+//! use vscode_generator::recommendations::Recommendations;
+//! # #[cfg(feature = "fs")]
+//! # fn main() -> vscode_generator::Result<()> {
+//! 
+//! Recommendations::new()
+//!     .add("rust-lang.rust-analyzer")
+//!     .add("tamasfe.even-better-toml")
+//!     .add_unwanted("rust-lang.rust")
+//!     .write_to("./.vscode/extensions.json")?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "fs"))] fn main() {}
+//! ```
+
+pub mod extension_recommendations;    pub use extension_recommendations::Recommendations;
@@ -0,0 +1,109 @@
+use crate::prelude::*;
+use crate::snippets::SnippetsFile;
+#[cfg(feature = "fs")]
+use std::{ fs, path::Path };
+use serde_json::{ json, Value };
+
+/// # The Extension Scaffold
+/// 
+/// 🏗️ Generates a minimal VS Code extension skeleton: the `package.json` manifest,
+/// the snippets files (wired into `contributes.snippets`) and the `.vscodeignore`.
+/// 
+/// ## Usage
+/// 
+/// ```rust
+/// # use vscode_generator::{ Snippet, SnippetsFile, scaffold::ExtensionScaffold };
+/// let scaffold = ExtensionScaffold::new("my-snippets", "me", "0.1.0")
+///     .add_snippets("rust", SnippetsFile::new(vec![
+///         Snippet::new("main", vec!["fn main() {", "    $0", "}"])
+///     ]));
+/// 
+/// let manifest = scaffold.to_package_json().unwrap();
+/// assert!(manifest.contains("./snippets/rust.code-snippets"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExtensionScaffold {
+    /// The extension name (lowercase, no spaces)
+    pub name: String,
+    /// The publisher identifier
+    pub publisher: String,
+    /// The extension version (semver)
+    pub version: String,
+    /// The snippets files by language id
+    pub snippets: Vec<(String, SnippetsFile)>,
+}
+
+impl ExtensionScaffold {
+    /// The minimal supported VS Code engine version
+    pub const ENGINE: &'static str = "^1.75.0";
+
+    /// Creates a new extension scaffold
+    pub fn new<S: Into<String>>(name: S, publisher: S, version: S) -> Self {
+        Self {
+            name: name.into(),
+            publisher: publisher.into(),
+            version: version.into(),
+            snippets: vec![],
+        }
+    }
+
+    /// Adds a snippets file for the language
+    pub fn add_snippets<S: Into<String>>(mut self, language: S, snippets: SnippetsFile) -> Self {
+        self.snippets.push((language.into(), snippets));
+        self
+    }
+
+    /// Returns the relative path of the language snippets file
+    fn snippets_path(language: &str) -> String {
+        format!("./snippets/{language}.code-snippets")
+    }
+
+    /// Converts the extension manifest to json string
+    pub fn to_package_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(&self.manifest()).map_err(Error::from)
+    }
+
+    /// Returns the extension manifest (extended with the other contributions by `Extension`)
+    pub(crate) fn manifest(&self) -> Value {
+        let snippets: Vec<_> = self.snippets
+            .iter()
+            .map(|(language, _)| json!({
+                "language": language,
+                "path": Self::snippets_path(language),
+            }))
+            .collect();
+
+        json!({
+            "name": self.name,
+            "displayName": self.name,
+            "version": self.version,
+            "publisher": self.publisher,
+            "engines": { "vscode": Self::ENGINE },
+            "categories": ["Snippets"],
+            "contributes": { "snippets": snippets },
+        })
+    }
+
+    /// `[fs]`: Writes the extension skeleton to the directory
+    #[cfg(feature = "fs")]
+    pub fn write_to_dir<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.write_with_manifest(path.as_ref(), &self.to_package_json()?)
+    }
+
+    /// Writes the manifest json, the `.vscodeignore` and the snippets files to the directory
+    #[cfg(feature = "fs")]
+    pub(crate) fn write_with_manifest(&self, dir: &Path, manifest: &str) -> Result<()> {
+        fs::create_dir_all(dir).map_err(Error::from)?;
+
+        // write the manifest:
+        fs::write(dir.join("package.json"), manifest).map_err(Error::from)?;
+        fs::write(dir.join(".vscodeignore"), ".vscode/**\n.gitignore\n**/*.map\n").map_err(Error::from)?;
+
+        // write the snippets:
+        for (language, snippets) in &self.snippets {
+            snippets.write_to(dir.join(Self::snippets_path(language)))?;
+        }
+
+        Ok(())
+    }
+}
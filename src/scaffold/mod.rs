@@ -0,0 +1,39 @@
+//! # The Scaffold Module
+//! 
+//! 🏗️ This module lays down a minimal but working VS Code extension skeleton,
+//! so the generated snippets can be published as an extension.
+//! 
+//! ## Components
+//! 
+//! - [`ExtensionScaffold`] - The extension skeleton generator
+//! 
+//! ## Examples
+//! 
+//! ```rust,no_run
+//! use vscode_generator::{ Snippet, SnippetsFile, scaffold::ExtensionScaffold };
+//! # #[cfg(feature = "fs")]
+//! # fn main() -> vscode_generator::Result<()> {
+//! 
+//! let snippets = SnippetsFile::new(vec![
+//!     Snippet::new("fn", vec!["fn $1() {", "    $0", "}"])
+//! ]);
+//! 
+//! ExtensionScaffold::new("rust-snippets", "SynapDrake", "0.1.0")
+//!     .add_snippets("rust", snippets)
+//!     .write_to_dir("./rust-snippets")?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "fs"))] fn main() {}
+//! ```
+//! 
+//! ## Output
+//! 
+//! ```text
+//! rust-snippets/
+//! ├── package.json
+//! ├── .vscodeignore
+//! └── snippets/
+//!     └── rust.code-snippets
+//! ```
+
+pub mod extension_scaffold;    pub use extension_scaffold::ExtensionScaffold;
@@ -0,0 +1,61 @@
+/// # The Comment Style
+///
+/// 💬 The comment markers of a language, used by `Snippet::todo_comment()`.
+/// The block markers are preferred when set (`/* TODO: ... */`).
+///
+/// ## Usage
+/// ```rust
+/// # use vscode_generator::{ Snippet, snippets::CommentStyle };
+/// let line = Snippet::todo_comment("todo", "TODO", Some("#")).build().unwrap();
+/// let block = Snippet::todo_comment("todo", "TODO", CommentStyle::block("/*", "*/")).build().unwrap();
+///
+/// assert_eq!(line.body, vec!["# TODO: ${1:...}"]);
+/// assert_eq!(block.body, vec!["/* TODO: ${1:...} */"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CommentStyle<'a> {
+    /// The line comment marker (e.g. `//`)
+    pub line: &'a str,
+    /// The block comment markers (e.g. `("/*", "*/")`)
+    pub block: Option<(&'a str, &'a str)>,
+}
+
+impl<'a> CommentStyle<'a> {
+    /// Creates the line comment style
+    pub fn line(marker: &'a str) -> Self {
+        Self { line: marker, block: None }
+    }
+
+    /// Creates the block comment style (the line marker is `//`)
+    pub fn block(open: &'a str, close: &'a str) -> Self {
+        Self { line: "//", block: Some((open, close)) }
+    }
+
+    /// Wraps the text into a comment (the block markers are preferred)
+    pub fn comment(&self, text: &str) -> String {
+        match self.block {
+            Some((open, close)) => format!("{open} {text} {close}"),
+            None => format!("{} {text}", self.line),
+        }
+    }
+}
+
+/// The `//` line comments
+impl Default for CommentStyle<'_> {
+    fn default() -> Self {
+        Self::line("//")
+    }
+}
+
+impl<'a> From<&'a str> for CommentStyle<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::line(value)
+    }
+}
+
+/// The line comment marker (`None` means `//`)
+impl<'a> From<Option<&'a str>> for CommentStyle<'a> {
+    fn from(value: Option<&'a str>) -> Self {
+        value.map(Self::line).unwrap_or_default()
+    }
+}
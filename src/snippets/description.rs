@@ -0,0 +1,90 @@
+use serde::{ Serialize, Deserialize };
+
+/// # The Snippet Description
+///
+/// 📝 The description shown in the IntelliSense detail pane. VS Code accepts both a single
+/// string and an array of lines, so a single-line description keeps serializing as a plain string.
+///
+/// ## Usage
+///
+/// ```rust
+/// # use vscode_generator::{ Snippet, snippets::Description };
+/// let snippet = Snippet::builder()
+///     .set_prefix("main")
+///     .add_line("fn main() {}")
+///     .set_description("Main function")
+///     .add_description_line("The entry point of a binary crate")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(
+///     snippet.description_raw(),
+///     Some(&Description::Multi(vec![
+///         "Main function".to_owned(),
+///         "The entry point of a binary crate".to_owned(),
+///     ]))
+/// );
+/// assert_eq!(snippet.description(), Some("Main function"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Description {
+    /// The single-line description (`"description": "..."`)
+    Single(String),
+    /// The multi-line description (`"description": ["...", "..."]`)
+    Multi(Vec<String>),
+}
+
+impl Description {
+    /// Returns the description lines
+    pub fn lines(&self) -> Vec<&str> {
+        match self {
+            Self::Single(line) => vec![line.as_str()],
+            Self::Multi(lines) => lines.iter().map(String::as_str).collect(),
+        }
+    }
+
+    /// Adds a new line (a single-line description becomes a multi-line one)
+    pub fn push_line<S: Into<String>>(&mut self, line: S) {
+        match self {
+            Self::Single(first) => *self = Self::Multi(vec![std::mem::take(first), line.into()]),
+            Self::Multi(lines) => lines.push(line.into()),
+        }
+    }
+
+    /// Appends the text to the last line
+    pub(crate) fn push_str(&mut self, text: &str) {
+        match self {
+            Self::Single(line) => line.push_str(text),
+            Self::Multi(lines) => match lines.last_mut() {
+                Some(line) => line.push_str(text),
+                None => lines.push(text.to_owned()),
+            },
+        }
+    }
+}
+
+/// Prints the description lines separated by newlines
+impl std::fmt::Display for Description {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.lines().join("\n"))
+    }
+}
+
+impl From<String> for Description {
+    fn from(value: String) -> Self {
+        Self::Single(value)
+    }
+}
+
+impl From<&str> for Description {
+    fn from(value: &str) -> Self {
+        Self::Single(value.to_owned())
+    }
+}
+
+impl From<Vec<String>> for Description {
+    fn from(value: Vec<String>) -> Self {
+        Self::Multi(value)
+    }
+}
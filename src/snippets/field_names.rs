@@ -0,0 +1,66 @@
+/// # The Field Names
+///
+/// 🏷️ The serialized names of the snippet fields (see `SnippetsFile::with_field_names()`),
+/// for the tools reusing the snippet shape with their own keys. The default names are VS Code's.
+///
+/// ## Usage
+/// ```rust
+/// # use vscode_generator::{ Snippet, SnippetsFile, snippets::FieldNames };
+/// let file = SnippetsFile::new(vec![Snippet::new("log", vec!["console.log($0);"])])
+///     .with_field_names(FieldNames::new().set_body("bodyLines"));
+///
+/// assert!(file.to_json().unwrap().contains("\"bodyLines\""));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldNames {
+    /// The name of the `prefix` field
+    pub prefix: String,
+    /// The name of the `body` field
+    pub body: String,
+    /// The name of the `description` field
+    pub description: String,
+    /// The name of the `scope` field
+    pub scope: String,
+}
+
+impl FieldNames {
+    /// Creates the VS Code field names
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the `prefix` field
+    pub fn set_prefix<S: Into<String>>(mut self, name: S) -> Self {
+        self.prefix = name.into();
+        self
+    }
+
+    /// Sets the name of the `body` field
+    pub fn set_body<S: Into<String>>(mut self, name: S) -> Self {
+        self.body = name.into();
+        self
+    }
+
+    /// Sets the name of the `description` field
+    pub fn set_description<S: Into<String>>(mut self, name: S) -> Self {
+        self.description = name.into();
+        self
+    }
+
+    /// Sets the name of the `scope` field
+    pub fn set_scope<S: Into<String>>(mut self, name: S) -> Self {
+        self.scope = name.into();
+        self
+    }
+}
+
+impl Default for FieldNames {
+    fn default() -> Self {
+        Self {
+            prefix: "prefix".to_owned(),
+            body: "body".to_owned(),
+            description: "description".to_owned(),
+            scope: "scope".to_owned(),
+        }
+    }
+}
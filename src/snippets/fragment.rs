@@ -0,0 +1,51 @@
+/// # The Body Fragment
+///
+/// 🧱 A named group of body lines shared by several snippets (a test harness, an error-handling
+/// tail, ...), appended by `SnippetBuilder::add_fragment()`.
+///
+/// ## Usage
+/// ```rust
+/// # use vscode_generator::{ Snippet, snippets::Fragment };
+/// let ok_tail = Fragment::new("ok tail", vec!["    Ok(())", "}"]);
+///
+/// let snippet = Snippet::builder()
+///     .set_prefix("test_io")
+///     .add_line("#[test]")
+///     .add_line("fn ${1:name}() -> std::io::Result<()> {")
+///     .add_line("    $0")
+///     .add_fragment(&ok_tail)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(snippet.body[3..], ["    Ok(())", "}"]);
+///
+/// // the same fragment one level deeper (e.g. inside a `mod tests`):
+/// assert_eq!(ok_tail.indented("    ").lines, ["        Ok(())", "    }"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fragment {
+    /// The fragment name (for the humans, it isn't serialized)
+    pub name: String,
+    /// The body lines
+    pub lines: Vec<String>,
+}
+
+impl Fragment {
+    /// Creates a new fragment
+    pub fn new<S: Into<String>>(name: S, lines: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            name: name.into(),
+            lines: lines.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns a copy of the fragment with the indentation prepended to each non-empty line
+    pub fn indented(&self, indent: &str) -> Self {
+        let lines = self.lines
+            .iter()
+            .map(|line| if line.is_empty() { String::new() } else { format!("{indent}{line}") })
+            .collect();
+
+        Self { name: self.name.clone(), lines }
+    }
+}
@@ -0,0 +1,89 @@
+/// The indentation style of a snippet body (see `Snippet::indentation_style()`)
+///
+/// The lines starting inside a multi-line string literal (`"..."` or `` `...` ``) are ignored,
+/// their leading whitespace is a part of the string, not the indentation. The quotes in the char
+/// literals (`'"'`) and the `//` comments don't open a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndentStyle {
+    /// There are no indented lines
+    None,
+    /// The lines are indented with tabs only
+    Tabs,
+    /// The lines are indented with spaces only, the width is the indentation unit (e.g. `4`)
+    Spaces(usize),
+    /// The indentation mixes tabs and spaces (see the `MixedIndentation` lint)
+    Mixed,
+}
+
+impl IndentStyle {
+    /// Detects the indentation style of the body lines
+    pub(crate) fn detect(body: &[String]) -> Self {
+        let (mut tabs, mut width) = (false, 0);
+
+        for indent in code_indents(body).filter_map(|(_, indent)| indent) {
+            let spaces = indent.chars().filter(|ch| *ch == ' ').count();
+            match (spaces, spaces == indent.len()) {
+                (0, _) => tabs = true,
+                (n, true) => width = gcd(width, n),
+                _ => return Self::Mixed,
+            }
+            if tabs && width > 0 {
+                return Self::Mixed;
+            }
+        }
+
+        match (tabs, width) {
+            (true, _) => Self::Tabs,
+            (false, 0) => Self::None,
+            (false, n) => Self::Spaces(n),
+        }
+    }
+}
+
+/// Returns the body line indices with their indentation (`None` for the lines starting inside a string literal)
+/// 
+/// The char literals (`'"'`, `'\''`) don't open a string, and the `//` line comments are skipped.
+pub(crate) fn code_indents(body: &[String]) -> impl Iterator<Item = (usize, Option<&str>)> {
+    // the delimiter of the open string literal:
+    let mut open = None;
+
+    body.iter().enumerate().map(move |(i, line)| {
+        let indent = open.is_none().then(|| {
+            let code = line.trim_start_matches([' ', '\t']);
+            &line[..line.len() - code.len()]
+        });
+
+        let chars: Vec<char> = line.chars().collect();
+        let mut j = 0;
+        while j < chars.len() {
+            match (open, chars[j]) {
+                (Some(_), '\\') => j += 1,
+                (Some(quote), ch) if ch == quote => open = None,
+                (None, '"' | '`') => open = Some(chars[j]),
+                (None, '/') if chars.get(j + 1) == Some(&'/') => break,
+                (None, '\'') => j += char_literal_len(&chars[j + 1..]),
+                _ => {}
+            }
+            j += 1;
+        }
+
+        (i, indent.filter(|indent| !indent.is_empty()))
+    })
+}
+
+/// Returns the length of the char literal after its opening `'` (`x'`, `\x'`), `0` if it's not a char literal (a lifetime, an apostrophe)
+fn char_literal_len(rest: &[char]) -> usize {
+    match rest {
+        ['\\', _, '\'', ..] => 3,
+        [ch, '\'', ..] if *ch != '\\' => 2,
+        _ => 0,
+    }
+}
+
+/// Returns the greatest common divisor (`gcd(0, n)` is `n`)
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
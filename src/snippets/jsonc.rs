@@ -0,0 +1,143 @@
+//! The JSON with comments (JSONC) support, VS Code allows comments and trailing commas in `.code-snippets` files
+
+/// A comment found in the JSONC document
+#[derive(Debug, Clone)]
+pub(crate) struct Comment {
+    /// The byte offset of the comment start
+    pub start: usize,
+    /// The byte offset after the comment end
+    pub end: usize,
+    /// The comment text without the markers
+    pub text: String,
+}
+
+/// Replaces the comments and the trailing commas with spaces (the byte offsets and the newlines are kept)
+pub(crate) fn strip(json: &str) -> (String, Vec<Comment>) {
+    let mut bytes = json.as_bytes().to_vec();
+    let mut comments = vec![];
+    let mut in_string = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match (bytes[i], bytes.get(i + 1)) {
+            (b'\\', _) if in_string => i += 1,
+            (b'"', _) => in_string = !in_string,
+            (b'/', Some(b'/')) if !in_string => {
+                let end = bytes[i..].iter().position(|&b| b == b'\n').map_or(bytes.len(), |n| i + n);
+                comments.push(comment(json, i, end, 2, 0));
+                blank(&mut bytes[i..end]);
+                i = end;
+                continue;
+            }
+            (b'/', Some(b'*')) if !in_string => {
+                let end = json[i + 2..].find("*/").map_or(bytes.len(), |n| i + 2 + n + 2);
+                comments.push(comment(json, i, end, 2, 2));
+                blank(&mut bytes[i..end]);
+                i = end;
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    // remove the trailing commas:
+    in_string = false;
+    i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if in_string => i += 1,
+            b'"' => in_string = !in_string,
+            b',' if !in_string => {
+                let next = bytes[i + 1..].iter().find(|b| !b.is_ascii_whitespace());
+                if matches!(next, Some(b'}' | b']')) {
+                    bytes[i] = b' ';
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    // only ASCII bytes were replaced with spaces:
+    (String::from_utf8(bytes).expect("JSONC stripping keeps valid UTF-8"), comments)
+}
+
+/// Creates the comment from its byte range (the marker lengths are cut off)
+fn comment(json: &str, start: usize, end: usize, open: usize, close: usize) -> Comment {
+    let inner = &json[start + open..end.saturating_sub(close).max(start + open)];
+
+    Comment { start, end, text: inner.trim().to_owned() }
+}
+
+/// Replaces the bytes with spaces (the newlines are kept)
+fn blank(bytes: &mut [u8]) {
+    for b in bytes.iter_mut().filter(|b| **b != b'\n') {
+        *b = b' ';
+    }
+}
+
+/// Finds the keys of the top-level object with their byte offsets
+pub(crate) fn top_level_keys(json: &str) -> Vec<(String, usize)> {
+    let bytes = json.as_bytes();
+    let mut keys = vec![];
+    let mut depth = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' => depth -= 1,
+            b'"' => {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+
+                let is_key = bytes[(i + 1).min(bytes.len())..]
+                    .iter()
+                    .find(|b| !b.is_ascii_whitespace())
+                    == Some(&b':');
+
+                if depth == 1 && is_key {
+                    if let Ok(key) = serde_json::from_str(&json[start..=i.min(bytes.len() - 1)]) {
+                        keys.push((key, start));
+                    }
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    keys
+}
+
+/// Returns the text of the comments right before the offset (only whitespace in between)
+pub(crate) fn leading_comment(json: &str, comments: &[Comment], offset: usize) -> Option<String> {
+    let mut lines = vec![];
+    let mut end = offset;
+
+    for comment in comments.iter().rev().filter(|comment| comment.end <= offset) {
+        if !json[comment.end..end].trim().is_empty() {
+            break;
+        }
+        lines.push(comment.text.as_str());
+        end = comment.start;
+    }
+
+    lines.reverse();
+    (!lines.is_empty()).then(|| lines.join(" "))
+}
+
+/// Converts the 1-based line and column (e.g. of a `serde_json` error) to a byte offset
+pub(crate) fn byte_offset(json: &str, line: usize, column: usize) -> usize {
+    let line_start: usize = json
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+
+    (line_start + column.saturating_sub(1)).min(json.len())
+}
@@ -0,0 +1,28 @@
+use super::Snippet;
+
+/// The source of the top-level JSON keys of a snippets file (see `SnippetsFile::key_strategy()`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum KeyStrategy {
+    /// The snippet key in the file, i.e. its name (e.g. the generated `snippet_1700000000000_0_abcdef`)
+    /// or the explicit key of `SnippetsFile::insert()`
+    #[default]
+    Name,
+    /// The snippet prefix, the file reads like a hand-written one (keys are the triggers)
+    Prefix,
+    /// The first line of the snippet description (the key is used if there's no description)
+    Description,
+}
+
+impl KeyStrategy {
+    /// Returns the preferred JSON key of the snippet (the taken keys are disambiguated by the file)
+    pub(crate) fn key<'a>(&self, name: &'a str, snippet: &'a Snippet) -> &'a str {
+        match self {
+            Self::Name => name,
+            Self::Prefix => &snippet.prefix,
+            Self::Description => snippet.description
+                .as_ref()
+                .and_then(|desc| desc.lines().first().copied())
+                .unwrap_or(name),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+/// The common VS Code language identifiers (built-in and popular extensions)
+/// 
+/// See the VS Code [Known language identifiers](https://code.visualstudio.com/docs/languages/identifiers)
+pub const KNOWN_LANGUAGE_IDS: &[&str] = &[
+    // built-in:
+    "abap", "bat", "bibtex", "clojure", "coffeescript", "c", "cpp", "csharp", "css", "cuda-cpp",
+    "d", "dart", "diff", "dockercompose", "dockerfile", "erlang", "fsharp", "git-commit", "git-rebase",
+    "go", "groovy", "handlebars", "haml", "haskell", "html", "ini", "java", "javascript",
+    "javascriptreact", "json", "jsonc", "julia", "latex", "less", "lua", "makefile", "markdown",
+    "objective-c", "objective-cpp", "ocaml", "pascal", "perl", "perl6", "php", "plaintext",
+    "powershell", "jade", "pug", "python", "r", "razor", "ruby", "rust", "scss", "sass",
+    "shaderlab", "shellscript", "slim", "sql", "stylus", "svelte", "swift", "typescript",
+    "typescriptreact", "tex", "vb", "vue", "vue-html", "xml", "xsl", "yaml",
+
+    // popular extensions:
+    "astro", "elixir", "graphql", "kotlin", "nix", "prisma", "proto", "scala", "solidity",
+    "terraform", "toml", "zig",
+];
+
+/// Checks if the language identifier (or each one of a comma-separated scope) is a known VS Code language id
+/// 
+/// Custom language extensions define their own identifiers, so an unknown id isn't necessarily an error.
+pub fn is_known_language_id(id: &str) -> bool {
+    id.split(',')
+        .map(str::trim)
+        .all(|id| KNOWN_LANGUAGE_IDS.contains(&id))
+}
+
+/// Returns the prefixes of the common built-in snippets of the language (VS Code and its popular language extensions)
+/// 
+/// The user snippets with these prefixes compete with the built-in ones in the suggestions list.
+/// The list isn't exhaustive (the extensions change their snippets), so it's meant for advisory lints.
+pub fn builtin_prefixes(language: &str) -> &'static [&'static str] {
+    match language.trim() {
+        "javascript" | "typescript" | "javascriptreact" | "typescriptreact" => &[
+            "for", "foreach", "forin", "forof", "function", "if", "ifelse", "new", "switch", "while",
+            "dowhile", "trycatch", "settimeout", "setinterval", "import", "log", "class", "ctor", "prop",
+            "get", "set", "region", "endregion",
+        ],
+        "python" => &["if", "ifelse", "for", "while", "try", "with", "def", "class", "lambda", "ifmain"],
+        "rust" => &["tfn", "tmod", "pd", "ppd"],
+        "go" => &["for", "forr", "if", "el", "ie", "func", "fmain", "tys", "tyi", "ff", "fp", "pkgm"],
+        "c" | "cpp" => &["for", "if", "else", "while", "do", "switch", "struct", "class", "enum", "namespace", "try", "main"],
+        "csharp" => &["for", "foreach", "if", "else", "while", "switch", "try", "class", "prop", "ctor", "cw"],
+        "java" => &["for", "foreach", "if", "ifelse", "while", "switch", "try", "class", "ctor", "main", "sysout", "syserr"],
+        _ => &[],
+    }
+}
+
+/// The common file extensions by their VS Code language ids
+const EXTENSION_LANGUAGE_IDS: &[(&str, &str)] = &[
+    ("rs", "rust"), ("py", "python"), ("pyw", "python"), ("js", "javascript"), ("mjs", "javascript"),
+    ("cjs", "javascript"), ("jsx", "javascriptreact"), ("ts", "typescript"), ("mts", "typescript"),
+    ("tsx", "typescriptreact"), ("c", "c"), ("h", "c"), ("cc", "cpp"), ("cpp", "cpp"), ("cxx", "cpp"),
+    ("hpp", "cpp"), ("hh", "cpp"), ("cs", "csharp"), ("fs", "fsharp"), ("go", "go"), ("java", "java"),
+    ("kt", "kotlin"), ("kts", "kotlin"), ("rb", "ruby"), ("php", "php"), ("swift", "swift"),
+    ("m", "objective-c"), ("mm", "objective-cpp"), ("lua", "lua"), ("pl", "perl"), ("r", "r"),
+    ("dart", "dart"), ("ex", "elixir"), ("exs", "elixir"), ("erl", "erlang"), ("hs", "haskell"),
+    ("ml", "ocaml"), ("jl", "julia"), ("scala", "scala"), ("zig", "zig"), ("sol", "solidity"),
+    ("html", "html"), ("htm", "html"), ("css", "css"), ("scss", "scss"), ("sass", "sass"),
+    ("less", "less"), ("vue", "vue"), ("svelte", "svelte"), ("astro", "astro"), ("xml", "xml"),
+    ("md", "markdown"), ("markdown", "markdown"), ("tex", "latex"), ("json", "json"),
+    ("jsonc", "jsonc"), ("yml", "yaml"), ("yaml", "yaml"), ("toml", "toml"), ("ini", "ini"),
+    ("sh", "shellscript"), ("bash", "shellscript"), ("zsh", "shellscript"), ("ps1", "powershell"),
+    ("bat", "bat"), ("cmd", "bat"), ("sql", "sql"), ("graphql", "graphql"), ("gql", "graphql"),
+    ("proto", "proto"), ("tf", "terraform"), ("nix", "nix"), ("prisma", "prisma"), ("txt", "plaintext"),
+];
+
+/// Returns the VS Code language id of a file extension (with or without the leading dot, e.g. `".rs"` → `"rust"`)
+pub fn language_id_from_extension(ext: &str) -> Option<&'static str> {
+    let ext = ext.trim_start_matches('.');
+    EXTENSION_LANGUAGE_IDS
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(ext))
+        .map(|(_, id)| *id)
+}
+
+/// # The Scope Presets
+/// 
+/// 🗂️ The logical groups of VS Code language ids, so the exact id spellings don't have to be memorized.
+/// VS Code itself only supports the comma-separated lists, so a preset expands to one.
+/// 
+/// ## Usage
+/// ```rust
+/// # use vscode_generator::{ Snippet, snippets::Scope };
+/// let snippet = Snippet::builder()
+///     .set_prefix("log")
+///     .add_line("console.log($0);")
+///     .set_scope(Scope::JavaScriptFamily)
+///     .build()
+///     .unwrap();
+/// 
+/// assert_eq!(snippet.scope(), Some("javascript,typescript,javascriptreact,typescriptreact"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// HTML, CSS and the JavaScript family
+    WebFrontend,
+    /// JavaScript and TypeScript (with the React variants)
+    JavaScriptFamily,
+    /// The stylesheet languages (CSS, SCSS, Sass, Less)
+    Stylesheets,
+    /// C and C++
+    CFamily,
+    /// The shell scripts (Bash, PowerShell, Batch)
+    Shell,
+    /// The markup languages (HTML, XML, Markdown)
+    Markup,
+    /// The configuration formats (JSON, JSONC, YAML, TOML, INI)
+    Config,
+    /// Any other language ids
+    Custom(Vec<String>),
+}
+
+impl Scope {
+    /// Returns the language ids of the preset
+    pub fn language_ids(&self) -> Vec<&str> {
+        match self {
+            Self::WebFrontend => vec!["html", "css", "javascript", "typescript", "javascriptreact", "typescriptreact"],
+            Self::JavaScriptFamily => vec!["javascript", "typescript", "javascriptreact", "typescriptreact"],
+            Self::Stylesheets => vec!["css", "scss", "sass", "less"],
+            Self::CFamily => vec!["c", "cpp"],
+            Self::Shell => vec!["shellscript", "powershell", "bat"],
+            Self::Markup => vec!["html", "xml", "markdown"],
+            Self::Config => vec!["json", "jsonc", "yaml", "toml", "ini"],
+            Self::Custom(ids) => ids.iter().map(String::as_str).collect(),
+        }
+    }
+
+    /// Returns the comma-separated scope string (e.g. `"c,cpp"`)
+    pub fn to_scope_string(&self) -> String {
+        self.language_ids().join(",")
+    }
+}
+
+impl From<Scope> for String {
+    fn from(value: Scope) -> Self {
+        value.to_scope_string()
+    }
+}
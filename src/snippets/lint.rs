@@ -0,0 +1,205 @@
+use super::{ Snippet, FinalTabstop, syntax, builtin_prefixes, indent_style::code_indents };
+use std::collections::BTreeMap;
+
+/// The maximum length of a body line (in characters) before the `LongLine` lint
+pub const MAX_LINE_LENGTH: usize = 120;
+
+/// The kind of a snippet lint
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LintKind {
+    /// A `$` that isn't followed by a tabstop, a placeholder or a variable name (e.g. `$foo`, `$ `)
+    UnescapedDollar,
+    /// A line indentation mixing tabs and spaces (or differing from the other lines),
+    /// i.e. the body [`IndentStyle`](super::IndentStyle) is `Mixed`
+    MixedIndentation,
+    /// The final tabstop (`$0`) is placed before some other tabstop
+    FinalTabstopNotLast,
+    /// There is no final tabstop (`$0`), the cursor ends at the end of the snippet
+    MissingFinalTabstop,
+    /// The line is longer than [`MAX_LINE_LENGTH`]
+    LongLine,
+    /// The prefix contains a newline or a tab (VS Code can't match it while typing)
+    PrefixControlChar,
+    /// The prefix starts or ends with a space (sometimes intended, e.g. `"pub "`)
+    PrefixWhitespace,
+    /// The tabstop indices aren't contiguous (`$1` and `$3` without `$2`), VS Code skips the missing ones
+    TabstopGap { missing: Vec<u32> },
+    /// The tabstop index has several placeholders with a default text (`${1:a}` and `${1:b}`),
+    /// the mirrors (`$1`) and the choices are fine
+    DuplicateTabstop(u32),
+    /// The prefix shadows a built-in snippet of the scope language (see [`builtin_prefixes()`](super::builtin_prefixes))
+    BuiltinPrefix,
+    /// The file template has numbered tabstops (`$1`, ...), only `$0` is expected on the file creation
+    FileTemplateTabstop,
+}
+
+/// # The Snippet Lint
+///
+/// ⚠️ A warning about a common snippet mistake, produced by [`lint()`].
+/// Unlike the syntax validation, lints never fail the build.
+///
+/// ## Usage
+/// ```rust
+/// # use vscode_generator::{ Snippet, snippets::{ lint, LintKind } };
+/// let snippet = Snippet::new("echo", vec!["echo $name"]);
+/// let kinds: Vec<_> = lint(&snippet).into_iter().map(|lint| lint.kind).collect();
+///
+/// assert_eq!(kinds, vec![LintKind::UnescapedDollar, LintKind::MissingFinalTabstop]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    /// The 1-based body line (`0` for the prefix lints)
+    pub line: usize,
+    /// The kind of the lint
+    pub kind: LintKind,
+    /// The human-readable message
+    pub message: String,
+}
+
+impl Lint {
+    /// Creates a new lint
+    fn new<S: Into<String>>(line: usize, kind: LintKind, message: S) -> Self {
+        Self { line, kind, message: message.into() }
+    }
+}
+
+/// Prints the lint as `line N: message`
+impl std::fmt::Display for Lint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Checks the snippet for the common mistakes
+///
+/// 🔎 The lints are ordered by line (the prefix first), the `$0` lints come last:
+/// - `PrefixControlChar` - a newline or a tab in the prefix
+/// - `PrefixWhitespace` - leading or trailing spaces in the prefix (filter it out if intended)
+/// - `BuiltinPrefix` - the prefix of a built-in snippet of the scope language
+/// - `UnescapedDollar` - a `$` before a non-digit (use `\\$` for a literal dollar)
+/// - `MixedIndentation` - tabs mixed with spaces
+/// - `LongLine` - lines longer than [`MAX_LINE_LENGTH`] characters
+/// - `TabstopGap` - missing indices in the tabstops sequence (`$1`, `$3`)
+/// - `DuplicateTabstop` - several placeholders with a default for the same index
+/// - `FileTemplateTabstop` - numbered tabstops in a file template
+/// - `FinalTabstopNotLast` - `$0` placed before another tabstop
+/// - `MissingFinalTabstop` - no `$0` at all
+pub fn lint(snippet: &Snippet) -> Vec<Lint> {
+    let mut lints = vec![];
+
+    if snippet.prefix.contains(['\n', '\t']) {
+        lints.push(Lint::new(0, LintKind::PrefixControlChar, "the prefix contains a newline or a tab"));
+    }
+    if snippet.prefix.starts_with(' ') || snippet.prefix.ends_with(' ') {
+        lints.push(Lint::new(0, LintKind::PrefixWhitespace, format!("the prefix {:?} starts or ends with a space", snippet.prefix)));
+    }
+    for language in snippet.scope.iter().flat_map(|scope| scope.split(',')) {
+        if builtin_prefixes(language).contains(&snippet.prefix.as_str()) {
+            let message = format!("the prefix {:?} competes with a built-in `{}` snippet", snippet.prefix, language.trim());
+            lints.push(Lint::new(0, LintKind::BuiltinPrefix, message));
+        }
+    }
+
+    // the indentation character of the first indented line:
+    let mut indent_char = None;
+
+    // the lines inside the multi-line string literals have no indentation:
+    for ((i, indent), text) in code_indents(&snippet.body).zip(&snippet.body) {
+        let line = i + 1;
+
+        if let Some(column) = find_unescaped_dollar(text) {
+            lints.push(Lint::new(line, LintKind::UnescapedDollar, format!("unescaped `$` at column {column}, use `\\$` for a literal dollar")));
+        }
+
+        if let Some(indent) = indent {
+            let expected = *indent_char.get_or_insert(indent.chars().next().unwrap());
+            if indent.chars().any(|ch| ch != expected) {
+                lints.push(Lint::new(line, LintKind::MixedIndentation, "the indentation mixes tabs and spaces"));
+            }
+        }
+
+        let length = text.chars().count();
+        if length > MAX_LINE_LENGTH {
+            lints.push(Lint::new(line, LintKind::LongLine, format!("the line is {length} characters long (max {MAX_LINE_LENGTH})")));
+        }
+    }
+
+    let nodes = syntax::parse(&snippet.body);
+
+    // the placeholders count by index:
+    let mut placeholders = BTreeMap::new();
+    syntax::walk(&nodes, &mut |node| {
+        if let syntax::Node::Placeholder(n, _) = node {
+            *placeholders.entry(*n).or_insert(0) += 1;
+        }
+    });
+
+    let tabstops = snippet.tabstops();
+    if let Some(&max) = tabstops.last() {
+        let missing: Vec<u32> = (1..max).filter(|n| !tabstops.contains(n)).collect();
+        if !missing.is_empty() {
+            let message = format!("the tabstops {missing:?} are missing before `${max}`");
+            lints.push(Lint::new(1, LintKind::TabstopGap { missing }, message));
+        }
+    }
+
+    for (n, _) in placeholders.into_iter().filter(|(n, count)| *n > 0 && *count > 1) {
+        let line = snippet.body.iter().position(|text| text.contains(&format!("${{{n}:"))).map_or(1, |i| i + 1);
+        lints.push(Lint::new(line, LintKind::DuplicateTabstop(n), format!("the tabstop `${n}` has several default texts")));
+    }
+
+    if snippet.is_file_template == Some(true) {
+        let mut first = None;
+        syntax::walk(&nodes, &mut |node| match node.index() {
+            Some(n) if n > 0 && first.is_none() => first = Some(n),
+            _ => {}
+        });
+
+        if let Some(n) = first {
+            let line = snippet.body.iter().position(|text| text.contains(&format!("${n}")) || text.contains(&format!("${{{n}"))).map_or(1, |i| i + 1);
+            let numbered: Vec<_> = tabstops.iter().filter(|n| **n > 0).collect();
+            let message = format!("the file template has the tabstops {numbered:?}, only `$0` is expected on the file creation");
+            lints.push(Lint::new(line, LintKind::FileTemplateTabstop, message));
+        }
+    }
+
+    match snippet.final_tabstop_status() {
+        FinalTabstop::Missing => {
+            lints.push(Lint::new(snippet.body.len(), LintKind::MissingFinalTabstop, "there is no final tabstop `$0`"));
+        }
+        _ => {
+            let mut after_final = None;
+            let mut seen_final = false;
+            syntax::walk(&nodes, &mut |node| match node.index() {
+                Some(0) => seen_final = true,
+                Some(n) if seen_final && after_final.is_none() => after_final = Some(n),
+                _ => {}
+            });
+
+            if let Some(n) = after_final {
+                let line = snippet.body.iter().position(|text| text.contains("$0") || text.contains("${0")).map_or(1, |i| i + 1);
+                lints.push(Lint::new(line, LintKind::FinalTabstopNotLast, format!("the final tabstop `$0` is placed before `${n}`")));
+            }
+        }
+    }
+
+    lints
+}
+
+/// Finds the 1-based column of the first `$` not followed by a digit, `{` or a variable name
+fn find_unescaped_dollar(text: &str) -> Option<usize> {
+    let mut chars = text.chars().enumerate().peekable();
+
+    while let Some((i, ch)) = chars.next() {
+        match ch {
+            '\\' => { chars.next(); }
+            '$' => match chars.peek() {
+                Some((_, next)) if next.is_ascii_digit() || next.is_ascii_uppercase() || matches!(next, '{' | '_') => {}
+                _ => return Some(i + 1),
+            },
+            _ => {}
+        }
+    }
+
+    None
+}
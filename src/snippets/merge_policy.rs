@@ -0,0 +1,11 @@
+/// The resolution of a snippet name taken in both collections (see `SnippetsFile::merge()`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MergePolicy {
+    /// Keeps the existing snippet, the incoming one is dropped
+    KeepExisting,
+    /// Replaces the existing snippet with the incoming one (the position is kept)
+    #[default]
+    Overwrite,
+    /// Keeps both, the incoming snippet name gets a suffix (`name_2`, like in `add_snippet()`)
+    KeepBoth,
+}
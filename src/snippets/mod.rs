@@ -7,7 +7,26 @@
 //! 
 //! - [`Snippet`] - Individual snippet representation
 //! - [`SnippetBuilder`] - Fluent builder for snippets
+//! - [`SnippetGroup`] - Shared defaults of a snippets family
 //! - [`SnippetsFile`] - Collection of snippets for VS Code
+//! - [`SnippetsDiff`] - Difference between two snippets collections
+//! - [`KeyStrategy`] - Top-level JSON keys of the snippets file
+//! - [`MergePolicy`] - Name conflicts resolution of merged collections
+//! - [`ScopeConflict`] - Snippets with the same prefix in the same language
+//! - [`CommentStyle`] - Line and block comment markers
+//! - [`Description`] - Single or multi-line snippet description
+//! - [`IndentStyle`] - Tabs or spaces indentation of a body
+//! - [`WriteOptions`] - File writing options (backups)
+//! - [`WriteReport`] - Summary of a written file
+//! - [`SnippetFileKind`] - Global or language snippets file
+//! - [`FieldNames`] - Serialized names of the snippet fields
+//! - [`user_snippets_dir`] - User snippets folder of a [`VsCodeVariant`]
+//! - [`TabStop`] - Typed tabstops, placeholders and choices
+//! - [`Placeholder`] - Nested placeholders tree
+//! - [`Fragment`] - Reusable body lines
+//! - [`Variable`] - Typed VS Code variables
+//! - [`validate_placeholders`] - Placeholders syntax validation
+//! - [`lint()`] - Warnings about common snippet mistakes
 //! 
 //! ## Overview
 //! 
@@ -20,7 +39,7 @@
 //! 
 //! #### 🎨 Creating a Simple Snippet
 //! ```rust
-//! use snippets::SnippetBuilder;
+//! use vscode_generator::snippets::SnippetBuilder;
 //! 
 //! let snippet = SnippetBuilder::new()
 //!     .set_prefix("fn")
@@ -33,7 +52,7 @@
 //! 
 //! #### 📁 Managing Snippet Collections
 //! ```rust
-//! use snippets::{ SnippetsFile, SnippetBuilder };
+//! use vscode_generator::snippets::{ SnippetsFile, SnippetBuilder };
 //! 
 //! let mut file = SnippetsFile::new(vec![
 //!     SnippetBuilder::new()
@@ -68,6 +87,7 @@
 //! - `prefix` is empty
 //! - `name` is empty
 //! - `body` is empty
+//! - a file template has no `description` or its first non-blank `body` line is indented
 //! 
 //! ## Notes
 //! 
@@ -84,6 +104,32 @@
 //! - 🔗 Structure [`SnippetFile`](snippets_file/struct.SnippetsFile.html) - For more flexible snippet construction
 //! - 🔗 VS Code [Snippet Guide](https://code.visualstudio.com/docs/editor/userdefinedsnippets)
 
-pub mod snippet;            pub use snippet::Snippet;
+pub mod snippet;            pub use snippet::{ Snippet, TryIntoSnippet };
 pub mod snippet_builder;    pub use snippet_builder::SnippetBuilder;
+pub mod snippet_group;      pub use snippet_group::SnippetGroup;
 pub mod snippets_file;      pub use snippets_file::SnippetsFile;
+pub mod snippets_diff;      pub use snippets_diff::SnippetsDiff;
+pub mod key_strategy;       pub use key_strategy::KeyStrategy;
+pub mod merge_policy;       pub use merge_policy::MergePolicy;
+pub mod scope_conflict;     pub use scope_conflict::{ ScopeConflict, ScopeConflictKind };
+pub mod comment_style;      pub use comment_style::CommentStyle;
+pub mod description;        pub use description::Description;
+pub mod indent_style;       pub use indent_style::IndentStyle;
+mod templates;
+mod jsonc;
+pub mod write_options;      pub use write_options::{ WriteOptions, BackupPolicy };
+pub mod write_report;       pub use write_report::WriteReport;
+pub mod snippet_file_kind;  pub use snippet_file_kind::SnippetFileKind;
+pub mod field_names;        pub use field_names::FieldNames;
+pub mod vscode_variant;     pub use vscode_variant::{ VsCodeVariant, user_snippets_dir };
+pub mod languages;          pub use languages::{ is_known_language_id, language_id_from_extension, builtin_prefixes, Scope };
+pub mod lint;               pub use lint::{ lint, Lint, LintKind };
+#[cfg(feature = "schema")]
+pub mod schema;
+#[cfg(feature = "schema")]
+pub use schema::SNIPPETS_SCHEMA;
+pub mod tabstop;            pub use tabstop::TabStop;
+pub mod placeholder;        pub use placeholder::Placeholder;
+pub mod fragment;           pub use fragment::Fragment;
+pub mod variable;           pub use variable::Variable;
+pub mod syntax;             pub use syntax::{ validate_placeholders, escape_text, FinalTabstop };
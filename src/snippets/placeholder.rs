@@ -0,0 +1,91 @@
+use super::{ escape_text, TabStop, Variable };
+
+/// # The Placeholder Tree
+///
+/// 🌳 A placeholder whose default text may contain other tabstops (`${1:foo ${2:bar}}`),
+/// rendered with the right nesting and escaping by `SnippetBuilder::add_placeholder()` or `to_string()`.
+///
+/// ## Usage
+/// ```rust
+/// # use vscode_generator::{ SnippetBuilder, snippets::{ Placeholder, TabStop } };
+/// let snippet = SnippetBuilder::new()
+///     .set_prefix("new")
+///     .add_line("let x = ")
+///     .add_placeholder(Placeholder::nested(1, [
+///         Placeholder::from("Vec::with_capacity("),
+///         Placeholder::nested(2, ["16"]),
+///         Placeholder::from(")"),
+///     ]))
+///     .add_tabstop(TabStop::Final)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(snippet.body, vec!["let x = ${1:Vec::with_capacity(${2:16})}$0"]);
+/// ```
+///
+/// ## Rendering
+///
+/// - `Text("a}")` → `a\}` (the literal text is escaped)
+/// - `TabStop(tabstop)` → the rendered [`TabStop`]
+/// - `Variable(variable)` → `$NAME` (see [`Variable`])
+/// - `Nested(1, [..])` → `${1:..}` with the rendered children, no children → `$1`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Placeholder {
+    /// A literal text
+    Text(String),
+    /// A typed tabstop without the nested ones (`$1`, `${1|a,b|}`, ...)
+    TabStop(TabStop),
+    /// A VS Code variable (`$TM_FILENAME`)
+    Variable(Variable),
+    /// The tabstop with a default made of the nested nodes (`${1:foo ${2:bar}}`)
+    Nested(u32, Vec<Placeholder>),
+}
+
+impl Placeholder {
+    /// Creates the nested placeholder from its child nodes (the strings become text nodes)
+    pub fn nested<P: Into<Placeholder>>(index: u32, children: impl IntoIterator<Item = P>) -> Self {
+        Self::Nested(index, children.into_iter().map(Into::into).collect())
+    }
+}
+
+impl std::fmt::Display for Placeholder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(text) => write!(f, "{}", escape_text(text)),
+            Self::TabStop(tabstop) => write!(f, "{tabstop}"),
+            Self::Variable(variable) => write!(f, "{variable}"),
+            Self::Nested(n, children) if children.is_empty() => write!(f, "${n}"),
+            Self::Nested(n, children) => {
+                write!(f, "${{{n}:")?;
+                for child in children {
+                    write!(f, "{child}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+impl From<TabStop> for Placeholder {
+    fn from(value: TabStop) -> Self {
+        Self::TabStop(value)
+    }
+}
+
+impl From<Variable> for Placeholder {
+    fn from(value: Variable) -> Self {
+        Self::Variable(value)
+    }
+}
+
+impl From<&str> for Placeholder {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_owned())
+    }
+}
+
+impl From<String> for Placeholder {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
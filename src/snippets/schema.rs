@@ -0,0 +1,46 @@
+use crate::prelude::*;
+
+/// The JSON schema of a VS Code snippets file (`.code-snippets` / `{language}.json`)
+///
+/// 📐 Follows the schema VS Code bundles for the user snippets files: a map of snippet names
+/// to snippet objects, where `body` is required and `prefix`, `body` and `description` may be
+/// a string or an array of strings.
+pub const SNIPPETS_SCHEMA: &str = r#"{
+    "$schema": "http://json-schema.org/draft-07/schema#",
+    "type": "object",
+    "additionalProperties": {
+        "type": "object",
+        "required": ["body"],
+        "properties": {
+            "prefix": {
+                "type": ["string", "array"],
+                "items": { "type": "string" }
+            },
+            "body": {
+                "type": ["string", "array"],
+                "items": { "type": "string" }
+            },
+            "description": {
+                "type": ["string", "array"],
+                "items": { "type": "string" }
+            },
+            "scope": { "type": "string" },
+            "isFileTemplate": { "type": "boolean" },
+            "priority": { "type": "integer", "minimum": 0 }
+        }
+    }
+}"#;
+
+/// Validates the JSON value against the [`SNIPPETS_SCHEMA`]
+///
+/// Returns [`Error::SchemaViolation`] with the JSON pointer of the first failing value.
+pub(crate) fn validate(value: &serde_json::Value) -> Result<()> {
+    let schema: serde_json::Value = serde_json::from_str(SNIPPETS_SCHEMA).map_err(Error::from)?;
+    let validator = jsonschema::validator_for(&schema).expect("the bundled snippets schema is valid");
+
+    let pointer = validator.iter_errors(value).next().map(|error| error.instance_path().to_string());
+    match pointer {
+        Some(pointer) => Err(Error::SchemaViolation(pointer)),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,46 @@
+/// The kind of a scope conflict
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ScopeConflictKind {
+    /// At least one snippet has no scope, so it's suggested in all the languages
+    Unscoped,
+    /// Both snippets are scoped to these languages
+    Overlapping(Vec<String>),
+}
+
+/// # The Scope Conflict
+///
+/// ⚔️ Two snippets with the same prefix suggested in the same language, made by
+/// `SnippetsFile::scope_conflicts()`. It explains why a language-specific snippet
+/// seems to be ignored: an unscoped one with the same trigger competes with it everywhere.
+///
+/// ## Usage
+/// ```rust
+/// # use vscode_generator::{ Snippet, SnippetsFile, snippets::ScopeConflictKind };
+/// let file = SnippetsFile::new(vec![
+///     Snippet::builder().set_name("rust_fn").set_prefix("fn").add_line("fn $1() {}").set_scope("rust"),
+///     Snippet::builder().set_name("any_fn").set_prefix("fn").add_line("function $1() {}"),
+/// ]);
+///
+/// let conflicts = file.scope_conflicts();
+/// assert_eq!(conflicts[0].names, ["rust_fn".to_owned(), "any_fn".to_owned()]);
+/// assert_eq!(conflicts[0].kind, ScopeConflictKind::Unscoped);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScopeConflict {
+    /// The shared prefix
+    pub prefix: String,
+    /// The names of the conflicting snippets (in the file order)
+    pub names: [String; 2],
+    /// The kind of the conflict
+    pub kind: ScopeConflictKind,
+}
+
+impl std::fmt::Display for ScopeConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [first, second] = &self.names;
+        match &self.kind {
+            ScopeConflictKind::Unscoped => write!(f, "'{}': '{first}' and '{second}' conflict in all languages", self.prefix),
+            ScopeConflictKind::Overlapping(scopes) => write!(f, "'{}': '{first}' and '{second}' conflict in {}", self.prefix, scopes.join(", ")),
+        }
+    }
+}
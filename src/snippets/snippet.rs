@@ -1,6 +1,7 @@
 use crate::prelude::*;
-use super::SnippetBuilder;
-use serde::Serialize;
+use super::{ SnippetBuilder, CommentStyle, Description, FinalTabstop, IndentStyle, syntax, validate_placeholders };
+use serde::{ Serialize, Deserialize };
+use std::collections::{ BTreeSet, HashMap };
 
 /// # The Snippet
 /// 
@@ -18,6 +19,7 @@ use serde::Serialize;
 /// ## Usage
 /// 
 /// ```rust
+/// # use vscode_generator::Snippet;
 /// // Simple snippet creation
 /// let snippet = Snippet::new("fn main", vec![
 ///     "fn main() {",
@@ -43,6 +45,7 @@ use serde::Serialize;
 /// ## Using Templates
 /// 
 /// ```rust
+/// # use vscode_generator::Snippet;
 /// // Text snippet
 /// let text = Snippet::text("hello", "println!(\"Hello, world!\");")
 ///     .set_description("Prints 'Hello, world!'")
@@ -63,6 +66,8 @@ use serde::Serialize;
 /// ## Rust-specific Templates
 /// 
 /// ```rust
+/// # use vscode_generator::Snippet;
+/// # #[cfg(feature = "rust")] {
 /// // Requires feature = ["rust"]
 /// 
 /// // Rust macro alias
@@ -76,11 +81,34 @@ use serde::Serialize;
 ///     .set_description("Common derive attributes")
 ///     .build()
 ///     .unwrap();
+/// # }
+/// ```
+/// 
+/// ## Other Language Templates
+/// 
+/// - 🌐 `features = ["web"]` - HTML and CSS (`html_tag`, `html5_boilerplate`, `css_rule`, `css_media`, ...)
+/// - 🗄️ `features = ["sql"]` - SQL (`sql_select`, `sql_insert`, `sql_create_table`, ...)
+/// - 🐚 `features = ["shell"]` - Shell/Bash (`bash_shebang`, `bash_if`, `bash_for`, `bash_function`, ...)
+/// - ⚙️ `features = ["cpp"]` - C and C++ (`cpp_include`, `cpp_main`, `cpp_class`, `c_struct`, ...)
+/// - ☕ `features = ["jvm"]` - Java and Kotlin (`java_class`, `java_main`, `kotlin_fun`, `kotlin_data_class`, ...)
+/// - 📝 `features = ["markdown"]` - Markdown (`md_link`, `md_code_block`, `md_table`, `md_frontmatter`)
+/// - 🔩 `features = ["config"]` - TOML and YAML (`toml_table`, `toml_kv`, `yaml_kv`, `yaml_list_item`)
+/// 
+/// ## Preview
+/// 
+/// ```rust
+/// # use vscode_generator::Snippet;
+/// let snippet = Snippet::new("fn", vec!["fn ${1:name}() {", "    $0", "}"]);
+/// 
+/// assert_eq!(snippet.to_string(), "fn:\nfn ${1:name}() {\n    $0\n}");
+/// assert_eq!(format!("{snippet:#}"), "fn:\nfn name() {\n    $0\n}");
+/// assert_eq!(snippet.preview(), "fn name() {\n    \n}");
 /// ```
 /// 
 /// ## JSON Conversion
 /// 
 /// ```rust
+/// # use vscode_generator::Snippet;
 /// let snippet = Snippet::new("fn", vec!["function() {", "    $0", "}"]);
 /// let json = snippet.to_json().unwrap();
 /// println!("{}", json);
@@ -93,6 +121,7 @@ use serde::Serialize;
 /// - `prefix` is empty
 /// - `name` is empty
 /// - `body` is empty
+/// - a file template has no `description` or its first non-blank `body` line is indented
 /// 
 /// ## Notes
 /// 
@@ -108,27 +137,32 @@ use serde::Serialize;
 /// 
 /// - 🔗 Structure [`SnippetFile`](../snippets_file/struct.SnippetsFile.html) - For more flexible snippet construction
 /// - 🔗 VS Code [Snippet Guide](https://code.visualstudio.com/docs/editor/userdefinedsnippets)
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Snippet {
-    /// Unique identifier for the snippet (not serialized)
-    #[serde(skip_serializing)]
+    /// Unique identifier for the snippet (not serialized, it's the key in the snippets file)
+    #[serde(skip)]
     pub name: String,
     /// The trigger text for the snippet
     pub prefix: String,
-    /// The actual content of the snippet
+    /// The actual content of the snippet (a single newline-joined string is split into lines on reading)
+    #[serde(deserialize_with = "deserialize_body")]
     pub body: Vec<String>,
-    /// Optional description of what the snippet does
+    /// Optional description of what the snippet does (a string or an array of lines)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
+    pub description: Option<Description>,
     /// Optional language scope (e.g., "rust")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<String>,
     /// Optional flag for file templates
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "isFileTemplate", skip_serializing_if = "Option::is_none")]
     pub is_file_template: Option<bool>,
     /// Optional priority in suggestion list
     #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<u32>,
+    /// Optional tags for organizing the snippets (VS Code ignores them, so they're only written
+    /// by `SnippetsFile::with_tags(true)`)
+    #[serde(default, skip_serializing)]
+    pub tags: Option<Vec<String>>,
 }
 
 impl Snippet {
@@ -146,15 +180,236 @@ impl Snippet {
         SnippetBuilder::new()
     }
 
+    /// Converts the snippet back to a builder for editing (all the fields, including the name, are kept)
+    /// 
+    /// ```rust
+    /// # use vscode_generator::Snippet;
+    /// let snippet = Snippet::new("pr", vec!["print!(\"$0\");"]);
+    /// let edited = snippet.to_builder()
+    ///     .map_line(0, |line| *line = line.replace("print!", "println!"))
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// 
+    /// assert_eq!(edited.name, snippet.name);
+    /// assert_eq!(edited.body, vec!["println!(\"$0\");"]);
+    /// ```
+    pub fn into_builder(self) -> SnippetBuilder {
+        self.into()
+    }
+
+    /// Creates a builder from a copy of the snippet (see `into_builder()`)
+    pub fn to_builder(&self) -> SnippetBuilder {
+        self.clone().into()
+    }
+
+    /// Returns the trigger text of the snippet
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns the body lines of the snippet
+    pub fn body(&self) -> &[String] {
+        &self.body
+    }
+
+    /// Returns the description of the snippet (if any), the first line of a multi-line one
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_ref().and_then(|desc| desc.lines().first().copied())
+    }
+
+    /// Returns the single or multi-line description of the snippet (if any)
+    pub fn description_raw(&self) -> Option<&Description> {
+        self.description.as_ref()
+    }
+
+    /// Returns the language scope of the snippet (if any)
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    /// Returns the tags of the snippet (empty if there are none)
+    pub fn tags(&self) -> &[String] {
+        self.tags.as_deref().unwrap_or_default()
+    }
+
+    /// Checks if the snippet has the tag
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags().iter().any(|t| t == tag)
+    }
+
     /// Converts the snippet to json string
     pub fn to_json(&self) -> Result<String> {
         serde_json::to_string_pretty(&self).map_err(Error::from)
     }
+
+    /// Converts the snippet to json value
+    pub fn to_value(&self) -> Result<serde_json::Value> {
+        serde_json::to_value(self).map_err(Error::from)
+    }
+
+    /// Shifts the snippet priority by `delta` (a missing priority counts as `0`)
+    pub fn with_relative_priority(mut self, delta: i64) -> Self {
+        let priority = self.priority.unwrap_or(0) as i64 + delta;
+        self.priority = Some(priority.clamp(0, u32::MAX as i64) as u32);
+        self
+    }
+
+    /// Validates the snippet body syntax (see [`validate_placeholders`](super::validate_placeholders))
+    pub fn validate_syntax(&self) -> Result<()> {
+        validate_placeholders(&self.body)
+    }
+
+    /// Renders the snippet body as the final code (all the tabstop markers are removed)
+    /// 
+    /// Placeholders and variables are replaced with their default text, choices with the first option.
+    pub fn preview(&self) -> String {
+        syntax::render(&syntax::parse(&self.body), &mut |_| None)
+    }
+
+    /// Expands the snippet body to a concrete code using the provided tabstop values
+    /// 
+    /// Each `$N`/`${N:default}`/`${N|...|}` is replaced with `values[N]`, falling back to the
+    /// default text (mirrored tabstops reuse it too), the first choice or an empty string.
+    /// The final tabstop `$0` is always removed.
+    /// 
+    /// ```rust
+    /// # use vscode_generator::Snippet;
+    /// # use std::collections::HashMap;
+    /// let snippet = Snippet::new("fn", vec!["fn ${1:name}(${2|a,b|}) -> ${3:Self} {", "    $0$1", "}"]);
+    /// let values = HashMap::from([(1, "new".to_owned())]);
+    /// 
+    /// assert_eq!(snippet.expand(&values), "fn new(a) -> Self {\n    new\n}");
+    /// ```
+    pub fn expand(&self, values: &HashMap<u32, String>) -> String {
+        let nodes = syntax::parse(&self.body);
+        let mut replace_value = |node: &syntax::Node| match node.index() {
+            Some(0) => Some(String::new()),
+            Some(n) => values.get(&n).cloned(),
+            None => None,
+        };
+
+        // the default text of the first placeholder is used by its mirrors:
+        let mut defaults = HashMap::new();
+        syntax::walk(&nodes, &mut |node| {
+            if let (Some(n), syntax::Node::Placeholder(..) | syntax::Node::Choice(..)) = (node.index(), node) {
+                defaults.entry(n).or_insert_with(|| syntax::render(std::slice::from_ref(node), &mut replace_value));
+            }
+        });
+
+        syntax::render(&nodes, &mut |node| match node {
+            syntax::Node::Tabstop(n) if *n != 0 => values.get(n).or(defaults.get(n)).cloned(),
+            _ => replace_value(node),
+        })
+    }
+
+    /// Returns the set of tabstop indices used in the snippet body (including `0`)
+    /// 
+    /// Parses the `$N`, `${N}`, `${N:...}` and `${N|...|}` forms (also the nested ones).
+    pub fn tabstops(&self) -> BTreeSet<u32> {
+        let mut tabstops = BTreeSet::new();
+        syntax::walk(&syntax::parse(&self.body), &mut |node| {
+            tabstops.extend(node.index());
+        });
+
+        tabstops
+    }
+
+    /// Checks how many final tabstops (`$0`) the snippet body defines
+    /// 
+    /// Escaped sequences (`\$0`) and choice options (`${1|$0,b|}`) are ignored.
+    pub fn final_tabstop_status(&self) -> FinalTabstop {
+        let mut count = 0;
+        syntax::walk(&syntax::parse(&self.body), &mut |node| {
+            if node.index() == Some(0) {
+                count += 1;
+            }
+        });
+
+        FinalTabstop::from_count(count)
+    }
+
+    /// Detects the body indentation: `Tabs`, `Spaces(width)`, `Mixed` or `None` (no indented lines)
+    /// 
+    /// The spaces width is the common unit of the indentations (`4` for the lines indented by 4 and 8).
+    /// The lines continuing a multi-line string literal are ignored.
+    pub fn indentation_style(&self) -> IndentStyle {
+        IndentStyle::detect(&self.body)
+    }
+}
+
+/// Deserializes the body from an array of lines or from a single newline-joined string
+fn deserialize_body<'de, D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Body {
+        Lines(Vec<String>),
+        Text(String),
+    }
+
+    Ok(match Body::deserialize(deserializer)? {
+        Body::Lines(lines) => lines,
+        Body::Text(text) => text.split('\n').map(|line| line.strip_suffix('\r').unwrap_or(line).to_owned()).collect(),
+    })
+}
+
+/// Prints the prefix and the snippet body
+/// 
+/// The alternate form (`{:#}`) prints the body in a preview form: the placeholder syntax
+/// is stripped to the default text (`${1:name}` → `name`), but the plain tabstops are still shown.
+impl std::fmt::Display for Snippet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}:", self.prefix)?;
+
+        if f.alternate() {
+            let body = syntax::render(&syntax::parse(&self.body), &mut |node| match node {
+                syntax::Node::Tabstop(n) => Some(format!("${n}")),
+                _ => None,
+            });
+            write!(f, "{body}")
+        } else {
+            write!(f, "{}", self.body.join("\n"))
+        }
+    }
 }
 
 impl From<SnippetBuilder> for Snippet {
     fn from(value: SnippetBuilder) -> Self {
-        value.build().unwrap()
+        value.build_with_context().unwrap()
+    }
+}
+
+/// Creates a snippet from the `(prefix, body)` pair, so `SnippetsFile::new(vec![("fn", vec!["fn $1() {", "}"])])` works
+impl From<(&str, Vec<&str>)> for Snippet {
+    fn from(value: (&str, Vec<&str>)) -> Self {
+        SnippetBuilder::from(value).into()
+    }
+}
+
+/// The fallible conversion into a Snippet (used by `SnippetsFile::try_new()`)
+/// 
+/// 💡 The std `TryInto<Snippet>` can't be used here: `From<SnippetBuilder>` already
+/// implements it with the `Infallible` error, which would unwrap the builder anyway.
+pub trait TryIntoSnippet {
+    /// Converts the value into a Snippet
+    fn try_into_snippet(self) -> Result<Snippet>;
+}
+
+impl TryIntoSnippet for Snippet {
+    fn try_into_snippet(self) -> Result<Snippet> {
+        Ok(self)
+    }
+}
+
+impl TryIntoSnippet for SnippetBuilder {
+    fn try_into_snippet(self) -> Result<Snippet> {
+        self.build_with_context()
+    }
+}
+
+impl TryIntoSnippet for (&str, Vec<&str>) {
+    fn try_into_snippet(self) -> Result<Snippet> {
+        SnippetBuilder::from(self).build_with_context()
     }
 }
 
@@ -168,12 +423,23 @@ impl Snippet {
     }
 
     /// Creates various comment templates (TODO, NOTE, etc.)
-    pub fn todo_comment<S: Into<String>>(prefix: S, comment_name: &str, comment_type: Option<&str>) -> SnippetBuilder {
-        let comment_type = comment_type.unwrap_or("//");
-        
+    /// 
+    /// The style is a line marker (`Some("#")`, `None` for `//`) or a [`CommentStyle`] with block markers.
+    pub fn todo_comment<'a, S: Into<String>>(prefix: S, comment_name: &str, comment_style: impl Into<CommentStyle<'a>>) -> SnippetBuilder {
+        let comment = comment_style.into().comment(&format!("{comment_name}: ${{1:...}}"));
+
         Self::builder()
             .set_prefix(prefix)
-            .set_body(vec![format!("{comment_type} {comment_name}: ${{1:...}}")])
+            .set_body(vec![comment])
+    }
+
+    /// Creates a file template (shown in the "New File..." picker, the description is its label)
+    pub fn file_template<S: Into<String>>(prefix: S, description: S, body: impl IntoIterator<Item = S>) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_description(description)
+            .set_body(body.into_iter().map(Into::into).collect())
+            .set_is_file_template(true)
     }
 
     /// Creates a function alias template
@@ -194,8 +460,8 @@ impl Snippet {
     }
 
     /// `[rust]`: Creates various comment templates (TODO, NOTE, etc.)
-    pub fn rust_todo_comment<S: Into<String>>(prefix: S, comment_name: &str, comment_type: Option<&str>) -> SnippetBuilder {
-        Self::todo_comment(prefix, comment_name, comment_type)
+    pub fn rust_todo_comment<'a, S: Into<String>>(prefix: S, comment_name: &str, comment_style: impl Into<CommentStyle<'a>>) -> SnippetBuilder {
+        Self::todo_comment(prefix, comment_name, comment_style)
             .set_scope("rust")
     }
 
@@ -215,6 +481,30 @@ impl Snippet {
             .set_scope("rust")
     }
 
+    /// `[rust]`: Creates the function alias templates from a `(prefix, fn_name)` table
+    pub fn rust_fn_aliases(aliases: &[(&str, &str)]) -> Vec<SnippetBuilder> {
+        aliases
+            .iter()
+            .map(|(prefix, fn_name)| Self::rust_fn_alias(*prefix, fn_name))
+            .collect()
+    }
+
+    /// `[rust]`: Creates the macro alias templates (with the default braces) from a `(prefix, macro_name)` table
+    pub fn rust_macro_aliases(aliases: &[(&str, &str)]) -> Vec<SnippetBuilder> {
+        aliases
+            .iter()
+            .map(|(prefix, macro_name)| Self::rust_macro_alias(*prefix, macro_name, None))
+            .collect()
+    }
+
+    /// `[rust]`: Creates a `Cargo.toml` dependency template
+    pub fn cargo_dependency<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec!["$1 = \"$2\""])
+            .set_scope("toml")
+    }
+
     /// `[rust]`: Creates a macro attribute template
     pub fn rust_attr<S: Into<String>>(prefix: S, attr_name: &str, attr_args: Vec<&str>) -> SnippetBuilder {
         Self::builder()
@@ -222,4 +512,106 @@ impl Snippet {
             .set_body(vec![format!("#[{attr_name}(${{1:{}}})]", attr_args.join("|"))])
             .set_scope("rust")
     }
+
+    /// `[rust]`: Creates a "surround with `unsafe {}`" template for the selected text
+    pub fn rust_wrap_unsafe<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .wrap_selection(&["unsafe {"], &["}$0"])
+            .set_description("Wrap in unsafe block")
+            .set_scope("rust")
+    }
+
+    /// `[rust]`: Creates a "surround with block" template for the selected text (e.g. `"if let Some($2) = $3"`)
+    pub fn rust_wrap_block<S: Into<String>>(prefix: S, header: &str) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .wrap_selection(&[&format!("{header} {{")], &["}$0"])
+            .set_scope("rust")
+    }
+
+    /// `[rust]`: Creates a function template from its signature (e.g. `"fn foo(a: i32, b: &str) -> bool"`)
+    /// 
+    /// Each argument name becomes a placeholder, the rest (qualifiers, generics, lifetimes, types
+    /// and the `where` clause) is kept as literal text. The prefix is the function name.
+    /// 
+    /// ```rust
+    /// # use vscode_generator::Snippet;
+    /// let snippet = Snippet::rust_from_signature("fn foo(a: i32, b: &str) -> bool").unwrap().build().unwrap();
+    /// assert_eq!(snippet.body, vec!["fn foo(${1:a}: i32, ${2:b}: &str) -> bool {", "    $0", "}"]);
+    /// ```
+    pub fn rust_from_signature(signature: &str) -> Result<SnippetBuilder> {
+        let error = |offset, message: &str| Error::Parse { offset, message: message.to_owned() };
+        let signature = signature.trim_end().trim_end_matches([';', '{']).trim_end();
+
+        // the `fn` keyword (after the optional `pub`, `async`, `unsafe`, etc.):
+        let fn_start = signature
+            .match_indices("fn ")
+            .map(|(i, _)| i)
+            .find(|&i| signature[..i].chars().next_back().is_none_or(char::is_whitespace))
+            .ok_or_else(|| error(0, "expected the `fn` keyword"))?;
+
+        let open = signature[fn_start..]
+            .find('(')
+            .map(|i| fn_start + i)
+            .ok_or_else(|| error(signature.len(), "expected the arguments list"))?;
+
+        let name = signature[fn_start + 3..open].split('<').next().unwrap_or_default().trim();
+        if name.is_empty() {
+            return Err(error(fn_start + 3, "expected the function name"));
+        }
+
+        let (commas, close) = rust_args_bounds(&signature[open + 1..])
+            .ok_or_else(|| error(open, "the arguments list isn't closed"))?;
+
+        // the arguments (the receivers like `&mut self` aren't placeholders):
+        let mut bounds = vec![0];
+        bounds.extend(commas.iter().map(|&i| i + 1));
+        let ends = commas.into_iter().chain([close]);
+
+        let mut index = 0;
+        let args: Vec<String> = bounds
+            .into_iter()
+            .zip(ends)
+            .map(|(start, end)| signature[open + 1 + start..open + 1 + end].trim())
+            .filter(|arg| !arg.is_empty())
+            .map(|arg| match arg.split_once(':') {
+                Some((pattern, ty)) if !ty.starts_with(':') => {
+                    index += 1;
+                    format!("${{{index}:{}}}:{}", syntax::escape_text(pattern.trim_end()), syntax::escape_text(ty))
+                }
+                _ => syntax::escape_text(arg),
+            })
+            .collect();
+
+        let head = syntax::escape_text(signature[..open].trim_start());
+        let tail = syntax::escape_text(&signature[open + 1 + close + 1..]);
+
+        Ok(Self::builder()
+            .set_prefix(name)
+            .set_body(vec![format!("{head}({}){tail} {{", args.join(", ")), "    $0".to_owned(), "}".to_owned()])
+            .set_scope("rust"))
+    }
+}
+
+/// Returns the byte offsets of the top-level commas and of the closing `)` of a Rust arguments list
+/// 
+/// The text starts right after the opening `(`, the `->` arrows (e.g. `impl Fn(i32) -> i32`) aren't brackets.
+#[cfg(feature = "rust")]
+fn rust_args_bounds(text: &str) -> Option<(Vec<usize>, usize)> {
+    let (mut depth, mut commas, mut prev) = (0, vec![], ' ');
+
+    for (i, ch) in text.char_indices() {
+        match ch {
+            '(' | '<' | '[' => depth += 1,
+            '>' if prev == '-' => {}
+            ')' if depth == 0 => return Some((commas, i)),
+            ')' | '>' | ']' => depth -= 1,
+            ',' if depth == 0 => commas.push(i),
+            _ => {}
+        }
+        prev = ch;
+    }
+
+    None
 }
@@ -1,6 +1,6 @@
 use crate::prelude::*;
-use super::Snippet;
-use std::{ time::SystemTime, fmt::Write };
+use super::{ Snippet, SnippetGroup, Description, TabStop, Placeholder, Fragment, Variable, is_known_language_id, language_id_from_extension, syntax };
+use std::{ time::SystemTime, fmt::Write, sync::atomic::{ AtomicU64, Ordering } };
 
 /// # The Snippet Builder
 /// 
@@ -16,6 +16,7 @@ use std::{ time::SystemTime, fmt::Write };
 /// 
 /// ## Usage
 /// ```rust
+/// # use vscode_generator::SnippetBuilder;
 /// let snippet = SnippetBuilder::new()
 ///     .set_prefix("fn main")
 ///     .add_line("fn main() {")
@@ -27,6 +28,7 @@ use std::{ time::SystemTime, fmt::Write };
 /// 
 /// ## 🛠️ Advanced Usage
 /// ```rust
+/// # use vscode_generator::SnippetBuilder;
 /// let snippet = SnippetBuilder::new()
 ///     .set_prefix("test")
 ///     .set_body(vec![
@@ -44,6 +46,7 @@ use std::{ time::SystemTime, fmt::Write };
 /// 
 /// ## 🔧 Body Manipulation
 /// ```rust
+/// # use vscode_generator::SnippetBuilder;
 /// let snippet = SnippetBuilder::new()
 ///     .set_prefix("hello")
 ///     .add_line("print!(\"Hello, world!\");")
@@ -53,6 +56,22 @@ use std::{ time::SystemTime, fmt::Write };
 ///     .map_line(1, |line| {
 ///         *line = line.replace("print!", "println!");
 ///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+/// ```
+/// 
+/// ## 📋 Template Builders
+/// ```rust
+/// # use vscode_generator::SnippetBuilder;
+/// let template = SnippetBuilder::new()
+///     .add_line("println!(\"$0\");")
+///     .set_scope("rust");
+/// 
+/// let print = template.with_prefix("print").build().unwrap();
+/// let debug = template.with_prefix("debug")
+///     .reset_body()
+///     .add_line("dbg!($0);")
 ///     .build()
 ///     .unwrap();
 /// ```
@@ -61,30 +80,62 @@ use std::{ time::SystemTime, fmt::Write };
 /// 
 /// #### 🏷️ Core Methods:
 /// - `new()` - Creates new builder instance
+/// - `Snippet::into_builder()` - Creates builder from existing snippet
 /// - `build()` - Constructs final Snippet
+/// - `build_validated()` - Constructs final Snippet with syntax validation
 /// - `validate()` - Checks builder state
+/// - `allow_empty_body(allow)` - Accepts prefix-only snippets
+/// - `require_sequential_tabstops()` - Rejects tabstop gaps and missing `$0`
 /// 
 /// #### 📝 Content Setting:
 /// - `set_name(name)` - Sets snippet name
 /// - `set_prefix(prefix)` - Sets trigger text
+/// - `with_prefix(prefix)` - Clones builder with new trigger text
+/// - `fan_out(prefixes)` - Builds one snippet per trigger text
 /// - `set_description(desc)` - Sets description
+/// - `add_description_line(line)` - Adds description line
+/// - `describe_from_body()` - Sets description from first body line
+/// - `with_scope_suffix_description()` - Appends scope to description
 /// - `set_scope(scope)` - Sets language scope
+/// - `set_scope_checked(scope)` - Sets known language scope
+/// - `set_scope_from_extension(ext)` - Sets scope by file extension
+/// - `set_scopes(scopes)` - Sets multiple language scopes
 /// - `set_priority(prio)` - Sets suggestion priority
+/// - `add_tag(tag)`, `set_tags(tags)` - Sets organizing tags
+/// - `set_description_opt(desc)`, `set_scope_opt(scope)`, `set_priority_opt(prio)` - Sets optional values
 /// 
 /// #### 📄 Body Manipulation:
 /// - `set_body(lines)` - Sets entire body content
+/// - `set_body_str(text)` - Sets body from multiline string
+/// - `set_body_template(template, mapping)` - Sets body with `{{name}}` tabstops
+/// - `reset_body()` - Clears body content
 /// - `add_line(line)` - Adds single line
 /// - `add_lines(lines)` - Adds multiple lines
+/// - `add_line_if(cond, line)`, `add_lines_if(cond, lines)` - Adds lines conditionally
+/// - `add_blank_line()` - Adds empty line
+/// - `add_tabstop(tabstop)` - Appends typed tabstop to last line
+/// - `add_placeholder(placeholder)` - Appends nested placeholder to last line
+/// - `add_variable(variable)` - Appends typed variable to last line
+/// - `add_fragment(fragment)` - Adds reusable lines
+/// - `wrap_selection(before, after)` - Adds lines around selected text
+/// - `ensure_trailing_newline()`, `ensure_no_trailing_newline()` - Controls trailing empty line
 /// - `set_line(n, line)` - Changes specific line
 /// - `map_body(fn)` - Transforms entire body
 /// - `map_line(n, fn)` - Transforms specific line
+/// - `map_lines(fn)` - Transforms every line
+/// - `try_set_line(n, line)`, `try_map_line(n, fn)` - Edits line in place
+/// - `comment_out(marker)` - Comments out non-empty lines
+/// - `trim_trailing_whitespace()` - Strips trailing spaces
+/// - `strip_trailing_blank_lines()` - Removes trailing empty lines
 /// 
 /// ## ⚠️ Validation Rules
 /// 
 /// Builder will fail if:
 /// - Name is empty
 /// - Prefix is empty
-/// - Body is empty
+/// - Body is empty (unless `allow_empty_body(true)`)
+/// - File template has no description or its first non-blank line is indented
+/// - Tabstops aren't `1..=max` with a single `$0` (with `require_sequential_tabstops()`)
 /// - Line index is out of bounds
 /// 
 /// ## 🎯 Best Practices
@@ -105,10 +156,13 @@ pub struct SnippetBuilder {
     name: String,
     prefix: String,
     body: Vec<String>,
-    description: Option<String>,
+    description: Option<Description>,
     scope: Option<String>,
     is_file_template: Option<bool>,
     priority: Option<u32>,
+    tags: Option<Vec<String>>,
+    allow_empty_body: bool,
+    sequential_tabstops: bool,
 }
 
 impl SnippetBuilder {
@@ -118,7 +172,11 @@ impl SnippetBuilder {
     }
 
     /// Generates a an unique name for snippet
+    /// 
+    /// The process-wide counter guarantees the uniqueness even within the same millisecond.
     pub fn gen_name() -> String {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
         // get current timestamp in milliseconds:
         let timestamp = SystemTime::now()
             .duration_since(SystemTime::UNIX_EPOCH)
@@ -131,7 +189,21 @@ impl SnippetBuilder {
             write!(&mut random_suffix, "{}", (b'a' + (fastrand::u8(0..26))) as char).unwrap();
         }
 
-        format!("snippet_{}_{}", timestamp, random_suffix)
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        format!("snippet_{}_{}_{}", timestamp, n, random_suffix)
+    }
+
+    /// Checks if the name looks like a `gen_name()` one (also with a disambiguating `_N` suffix)
+    pub(crate) fn is_generated_name(name: &str) -> bool {
+        let Some(rest) = name.strip_prefix("snippet_") else { return false };
+        let parts: Vec<&str> = rest.split('_').collect();
+        let digits = |part: &&str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+
+        parts.len() >= 3
+            && parts[..2].iter().all(digits)
+            && parts[2].len() == 6 && parts[2].bytes().all(|b| b.is_ascii_lowercase())
+            && parts[3..].iter().all(digits)
     }
 
     /// Validates the builder state
@@ -142,13 +214,54 @@ impl SnippetBuilder {
         if self.prefix.is_empty() {
             return Err(Error::PrefixIsRequired);
         }
-        if self.body.is_empty() {
+        if self.body.is_empty() && !self.allow_empty_body {
             return Err(Error::BodyIsEmpty);
         }
+        if self.is_file_template == Some(true) {
+            Self::validate_file_template(self.description.as_ref(), &self.body)?;
+        }
+        if self.sequential_tabstops {
+            syntax::validate_sequential_tabstops(&self.body)?;
+        }
 
         Ok(())
     }
 
+    /// Checks the file template rules: a description is required and the body starts at column zero
+    /// 
+    /// "Starts at column zero" means the first non-blank body line has no leading whitespace.
+    /// The following lines may be indented relative to it (e.g. a function body), the leading
+    /// blank lines are skipped, so `["", "    fn main() {}"]` is rejected as well.
+    pub(crate) fn validate_file_template(description: Option<&Description>, body: &[String]) -> Result<()> {
+        // the description is the label in the "New File" picker:
+        if description.is_none() {
+            return Err(Error::DescriptionIsRequired);
+        }
+        let first = body.iter().find(|line| !line.trim().is_empty());
+        if first.is_some_and(|line| line.starts_with(char::is_whitespace)) {
+            return Err(Error::FileTemplateIndented);
+        }
+
+        Ok(())
+    }
+
+    /// Allows (or forbids) building the snippet with an empty body (the completion-only snippets)
+    /// 
+    /// The body is required by default, so an accidentally empty one fails with `Error::BodyIsEmpty`.
+    pub fn allow_empty_body(mut self, allow: bool) -> Self {
+        self.allow_empty_body = allow;
+        self
+    }
+
+    /// Makes the build fail unless the tabstops are exactly `1..=max` with a single `$0`
+    /// 
+    /// It's a strict form of the `TabstopGap` and `MissingFinalTabstop` lints for the teams enforcing
+    /// the style, the error is `Error::InvalidSnippetSyntax` pointing to the offending tabstop.
+    pub fn require_sequential_tabstops(mut self) -> Self {
+        self.sequential_tabstops = true;
+        self
+    }
+
     /// Builds the Snippet instance
     pub fn build(self) -> Result<Snippet> {
         self.validate()?;
@@ -161,9 +274,25 @@ impl SnippetBuilder {
             scope: self.scope,
             is_file_template: self.is_file_template,
             priority: self.priority,
+            tags: self.tags,
         })
     }
 
+    /// Builds the Snippet instance, wrapping the error with the snippet prefix (or name, if the prefix is empty)
+    pub(crate) fn build_with_context(self) -> Result<Snippet> {
+        let prefix = if self.prefix.is_empty() { self.name.clone() } else { self.prefix.clone() };
+
+        self.build().map_err(|e| Error::SnippetBuild { prefix, source: Box::new(e) })
+    }
+
+    /// Builds the Snippet instance and validates its body syntax (unclosed placeholders, bad choices, etc.)
+    pub fn build_validated(self) -> Result<Snippet> {
+        let snippet = self.build()?;
+        snippet.validate_syntax()?;
+
+        Ok(snippet)
+    }
+
     /// Sets the name of the snippet
     pub fn set_name<S: Into<String>>(mut self, name: S) -> Self {
         self.name = name.into();
@@ -176,12 +305,105 @@ impl SnippetBuilder {
         self
     }
 
+    /// Clones the builder with a new prefix and a regenerated name (useful for template builders)
+    pub fn with_prefix<S: Into<String>>(&self, prefix: S) -> Self {
+        self.clone()
+            .set_name(Self::gen_name())
+            .set_prefix(prefix)
+    }
+
+    /// Builds a separate snippet for each prefix (with a regenerated name, see `with_prefix()`)
+    /// 
+    /// Unlike a multi-prefix snippet, each entry is listed in IntelliSense on its own.
+    /// The empty prefixes are skipped.
+    /// 
+    /// ## Panics
+    /// 
+    /// The builder is validated once before the loop (the prefix is the only difference between
+    /// the snippets), it panics if the builder is invalid (e.g. the body is empty).
+    pub fn fan_out(self, prefixes: &[&str]) -> Vec<Snippet> {
+        if let Err(e) = self.with_prefix("fan_out").validate() {
+            panic!("Failed to fan out the snippet: {e:?}");
+        }
+
+        prefixes
+            .iter()
+            .filter(|prefix| !prefix.is_empty())
+            .map(|prefix| self.with_prefix(*prefix).build().expect("the builder is validated"))
+            .collect()
+    }
+
     /// Sets the entire body of the snippet
     pub fn set_body<S: Into<String>>(mut self, body: Vec<S>) -> Self {
         self.body = body.into_iter().map(Into::into).collect();
         self
     }
 
+    /// Sets the body from a multiline template with the `{{name}}` holes mapped to the tabstops
+    /// 
+    /// The first hole of a name becomes the `${N:name}` placeholder, the next ones mirror it (`$N`).
+    /// The unmapped holes are kept as is, the lines are split like in `set_body_str()`.
+    /// 
+    /// ```rust
+    /// # use vscode_generator::Snippet;
+    /// let snippet = Snippet::builder()
+    ///     .set_prefix("getter")
+    ///     .set_body_template("fn {{field}}(&self) -> &{{type}} {\n    &self.{{field}}\n}", &[("field", 1), ("type", 2)])
+    ///     .build()
+    ///     .unwrap();
+    /// 
+    /// assert_eq!(snippet.body, vec!["fn ${1:field}(&self) -> &${2:type} {", "    &self.$1", "}"]);
+    /// ```
+    pub fn set_body_template(self, template: &str, mapping: &[(&str, u32)]) -> Self {
+        let mut body = String::with_capacity(template.len());
+        let mut used = vec![];
+        let mut rest = template;
+
+        while let Some(start) = rest.find("{{") {
+            let hole = rest[start + 2..].find("}}").map(|end| &rest[start + 2..start + 2 + end]);
+            let index = hole.and_then(|name| mapping.iter().find(|(key, _)| *key == name.trim()));
+
+            match (hole, index) {
+                (Some(name), Some(&(key, n))) => {
+                    body.push_str(&rest[..start]);
+                    match used.contains(&n) {
+                        true => write!(body, "${n}").unwrap(),
+                        false => write!(body, "${{{n}:{}}}", syntax::escape_text(key)).unwrap(),
+                    }
+                    used.push(n);
+                    rest = &rest[start + 2 + name.len() + 2..];
+                }
+                _ => {
+                    body.push_str(&rest[..start + 2]);
+                    rest = &rest[start + 2..];
+                }
+            }
+        }
+        body.push_str(rest);
+
+        self.set_body_str(body)
+    }
+
+    /// Sets the entire body of the snippet from a multiline string
+    /// 
+    /// The `\r\n` line endings are normalized and a trailing empty line is stripped.
+    pub fn set_body_str<S: AsRef<str>>(mut self, text: S) -> Self {
+        let text = text.as_ref().replace("\r\n", "\n");
+        let text = text.strip_suffix('\n').unwrap_or(&text);
+
+        self.body = match text.is_empty() {
+            true => vec![],
+            false => text.split('\n').map(String::from).collect(),
+        };
+        self
+    }
+
+    /// Clears the snippet body
+    pub fn reset_body(mut self) -> Self {
+        self.body.clear();
+        self
+    }
+
     /// Map snippet body using a transformation function that gets mutable reference
     pub fn map_body<F>(mut self, mut f: F) -> Self 
     where 
@@ -191,44 +413,216 @@ impl SnippetBuilder {
         self
     }
 
+    /// Map every body line using a transformation function (e.g. replace `TODO` with a team tag)
+    pub fn map_lines<F>(mut self, f: F) -> Self
+    where 
+        F: FnMut(&mut String)
+    {
+        self.body.iter_mut().for_each(f);
+        self
+    }
+
+    /// Comments out the non-empty body lines with the line comment marker (`x` → `// x`)
+    /// 
+    /// The lines already starting with the marker (after the indentation) aren't commented twice.
+    pub fn comment_out(mut self, marker: &str) -> Self {
+        for line in self.body.iter_mut().filter(|line| !line.is_empty()) {
+            if !line.trim_start().starts_with(marker) {
+                line.insert_str(0, &format!("{marker} "));
+            }
+        }
+        self
+    }
+
+    /// Strips the trailing spaces and tabs of each body line (the indentation is kept)
+    pub fn trim_trailing_whitespace(mut self) -> Self {
+        for line in &mut self.body {
+            line.truncate(line.trim_end_matches([' ', '\t']).len());
+        }
+        self
+    }
+
+    /// Removes the empty lines at the end of the body (VS Code inserts them as extra newlines)
+    /// 
+    /// The build fails with `Error::BodyIsEmpty` if no lines are left.
+    pub fn strip_trailing_blank_lines(mut self) -> Self {
+        while self.body.last().is_some_and(String::is_empty) {
+            self.body.pop();
+        }
+        self
+    }
+
     /// Adds a single line to the snippet body
     pub fn add_line<S: Into<String>>(mut self, line: S) -> Self {
         self.body.push(line.into());
         self
     }
 
+    /// Adds a single line to the snippet body if the condition is true
+    pub fn add_line_if<S: Into<String>>(self, cond: bool, line: S) -> Self {
+        if cond { self.add_line(line) } else { self }
+    }
+
+    /// Appends the rendered tabstop to the last body line (a new line is added if the body is empty)
+    pub fn add_tabstop(mut self, tabstop: TabStop) -> Self {
+        match self.body.last_mut() {
+            Some(line) => line.push_str(&tabstop.to_string()),
+            None => self.body.push(tabstop.to_string()),
+        }
+        self
+    }
+
+    /// Appends the rendered placeholder tree to the last body line (a new line is added if the body is empty)
+    pub fn add_placeholder(mut self, placeholder: Placeholder) -> Self {
+        match self.body.last_mut() {
+            Some(line) => line.push_str(&placeholder.to_string()),
+            None => self.body.push(placeholder.to_string()),
+        }
+        self
+    }
+
+    /// Appends the variable (`$NAME`) to the last body line (a new line is added if the body is empty)
+    pub fn add_variable(mut self, variable: Variable) -> Self {
+        match self.body.last_mut() {
+            Some(line) => line.push_str(&variable.to_string()),
+            None => self.body.push(variable.to_string()),
+        }
+        self
+    }
+
+    /// Adds the fragment lines to the snippet body (use `Fragment::indented()` to re-indent them)
+    pub fn add_fragment(mut self, fragment: &Fragment) -> Self {
+        self.body.extend(fragment.lines.iter().cloned());
+        self
+    }
+
+    /// Adds an empty line to the snippet body (VS Code keeps the explicit empty lines)
+    pub fn add_blank_line(mut self) -> Self {
+        self.body.push(String::new());
+        self
+    }
+
+    /// Ends the body with exactly one empty line, so the snippet inserts a trailing newline
+    /// 
+    /// Does nothing if the body is empty.
+    pub fn ensure_trailing_newline(self) -> Self {
+        if self.body.is_empty() {
+            return self;
+        }
+
+        self.strip_trailing_blank_lines().add_blank_line()
+    }
+
+    /// Removes the trailing empty lines, so the snippet ends right after its last line
+    pub fn ensure_no_trailing_newline(self) -> Self {
+        self.strip_trailing_blank_lines()
+    }
+
+    /// Adds the lines wrapping the selected text (`${TM_SELECTED_TEXT:$1}`) for "surround with" snippets
+    /// 
+    /// The selection line gets the indentation of the last `before` line, one level (4 spaces)
+    /// deeper if that line opens a bracket (`{`, `(`, `[`).
+    pub fn wrap_selection(mut self, before: &[&str], after: &[&str]) -> Self {
+        let indent = match before.last() {
+            Some(line) => {
+                let base = &line[..line.len() - line.trim_start().len()];
+                match line.trim_end().ends_with(['{', '(', '[']) {
+                    true => format!("{base}    "),
+                    false => base.to_owned(),
+                }
+            }
+            None => String::new(),
+        };
+
+        self.body.extend(before.iter().map(|line| line.to_string()));
+        self.body.push(format!("{indent}${{TM_SELECTED_TEXT:$1}}"));
+        self.body.extend(after.iter().map(|line| line.to_string()));
+        self
+    }
+
     /// Adds multiple lines to the snippet body
     pub fn add_lines<S: Into<String>>(mut self, lines: impl IntoIterator<Item = S>) -> Self {
         self.body.extend(lines.into_iter().map(Into::into));
         self
     }
 
+    /// Adds multiple lines to the snippet body if the condition is true
+    pub fn add_lines_if<S: Into<String>>(self, cond: bool, lines: impl IntoIterator<Item = S>) -> Self {
+        if cond { self.add_lines(lines) } else { self }
+    }
+
     /// Edits a specific line in the snippet body
     pub fn set_line<S: Into<String>>(mut self, n: usize, line: S) -> Result<Self> {
-        if n >= self.body.len() {
-            return Err(Error::IndexOutOfBounds(n));
-        }
-        
-        self.body[n] = line.into();
+        self.try_set_line(n, line)?;
         Ok(self)
     }
 
+    /// Edits a specific line in place (the builder is kept on error)
+    pub fn try_set_line<S: Into<String>>(&mut self, n: usize, line: S) -> Result<()> {
+        self.try_map_line(n, |old| *old = line.into())
+    }
+
     /// Map specific line using a transformation function
     pub fn map_line<F>(mut self, n: usize, mut f: F) -> Result<Self>
     where 
         F: FnMut(&mut String)
     {
-        if n >= self.body.len() {
-            return Err(Error::IndexOutOfBounds(n));
-        }
-
-        f(&mut self.body[n]);
+        self.try_map_line(n, &mut f)?;
         Ok(self)
     }
 
+    /// Maps specific line in place (the builder is kept on error)
+    pub fn try_map_line<F>(&mut self, n: usize, f: F) -> Result<()>
+    where 
+        F: FnOnce(&mut String)
+    {
+        let line = self.body.get_mut(n).ok_or(Error::IndexOutOfBounds(n))?;
+        f(line);
+        Ok(())
+    }
+
     /// Sets the description of the snippet
     pub fn set_description<S: Into<String>>(mut self, description: S) -> Self {
-        self.description = Some(description.into());
+        self.description = Some(Description::Single(description.into()));
+        self
+    }
+
+    /// Adds a new description line (the description becomes multi-line if it's already set)
+    pub fn add_description_line<S: Into<String>>(mut self, line: S) -> Self {
+        match &mut self.description {
+            Some(description) => description.push_line(line),
+            None => self.description = Some(Description::Single(line.into())),
+        }
+        self
+    }
+
+    /// Sets (or clears) the optional description of the snippet
+    pub fn set_description_opt<S: Into<String>>(mut self, description: Option<S>) -> Self {
+        self.description = description.map(|desc| Description::Single(desc.into()));
+        self
+    }
+
+    /// Sets the description from the first non-empty body line (if no description is set)
+    /// 
+    /// The placeholders are replaced with their default text (`fn ${1:name}()` → `fn name()`),
+    /// so call it after the body is set.
+    pub fn describe_from_body(mut self) -> Self {
+        if self.description.is_none() {
+            self.description = self.body
+                .iter()
+                .find(|line| !line.trim().is_empty())
+                .map(|line| syntax::render(&syntax::parse(std::slice::from_ref(line)), &mut |_| None).trim().into());
+        }
+        self
+    }
+
+    /// Appends the scope name to the description (e.g. `"Main function (rust)"`)
+    /// 
+    /// Does nothing if the description or the scope isn't set, so call it after both of them.
+    pub fn with_scope_suffix_description(mut self) -> Self {
+        if let (Some(description), Some(scope)) = (&mut self.description, &self.scope) {
+            description.push_str(&format!(" ({scope})"));
+        }
         self
     }
 
@@ -238,6 +632,58 @@ impl SnippetBuilder {
         self
     }
 
+    /// Sets (or clears) the optional scope of the snippet
+    pub fn set_scope_opt<S: Into<String>>(mut self, scope: Option<S>) -> Self {
+        self.scope = scope.map(Into::into);
+        self
+    }
+
+    /// Sets the multiple language scopes of the snippet (e.g. `["c", "cpp"]` → `"c,cpp"`)
+    pub fn set_scopes<S: AsRef<str>>(self, scopes: impl IntoIterator<Item = S>) -> Self {
+        let scopes: Vec<_> = scopes.into_iter().map(|scope| scope.as_ref().to_owned()).collect();
+        self.set_scope(scopes.join(","))
+    }
+
+    /// Sets the scope of the snippet from a file extension (e.g. `"rs"` → `"rust"`, `".py"` → `"python"`)
+    /// 
+    /// The unknown extensions are used as the language id verbatim (without the leading dot).
+    pub fn set_scope_from_extension(self, ext: &str) -> Self {
+        let scope = language_id_from_extension(ext).unwrap_or(ext.trim_start_matches('.'));
+        self.set_scope(scope)
+    }
+
+    /// Sets the scope of the snippet, failing on the unknown language ids (e.g. typos like `"rist"`)
+    /// 
+    /// Use `set_scope()` for the custom language ids.
+    pub fn set_scope_checked<S: Into<String>>(self, scope: S) -> Result<Self> {
+        let scope = scope.into();
+        if let Some(id) = scope.split(',').map(str::trim).find(|id| !is_known_language_id(id)) {
+            return Err(Error::UnknownLanguage(id.to_owned()));
+        }
+
+        Ok(self.set_scope(scope))
+    }
+
+    /// Fills the unset scope and priority from the group and prepends its description prefix
+    pub(crate) fn with_group_defaults(mut self, group: &SnippetGroup) -> Self {
+        if self.scope.is_none() {
+            self.scope = group.scope.clone();
+        }
+        if self.priority.is_none() {
+            self.priority = group.priority;
+        }
+        if let (Some(description), Some(prefix)) = (&mut self.description, &group.description_prefix) {
+            match description {
+                Description::Single(line) => line.insert_str(0, prefix),
+                Description::Multi(lines) => match lines.first_mut() {
+                    Some(line) => line.insert_str(0, prefix),
+                    None => lines.push(prefix.clone()),
+                },
+            }
+        }
+        self
+    }
+
     /// Sets whether this snippet is a file template
     pub fn set_is_file_template(mut self, is_template: bool) -> Self {
         self.is_file_template = Some(is_template);
@@ -249,6 +695,28 @@ impl SnippetBuilder {
         self.priority = Some(priority);
         self
     }
+
+    /// Adds a tag to the snippet (e.g. `"async"`), the repeated tags are skipped
+    pub fn add_tag<S: Into<String>>(mut self, tag: S) -> Self {
+        let tag = tag.into();
+        let tags = self.tags.get_or_insert_with(Vec::new);
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+        self
+    }
+
+    /// Sets the tags of the snippet
+    pub fn set_tags<S: Into<String>>(mut self, tags: impl IntoIterator<Item = S>) -> Self {
+        self.tags = Some(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets (or clears) the optional priority of the snippet
+    pub fn set_priority_opt(mut self, priority: Option<u32>) -> Self {
+        self.priority = priority;
+        self
+    }
 }
 
 impl Default for SnippetBuilder {
@@ -261,6 +729,54 @@ impl Default for SnippetBuilder {
             scope: None,
             is_file_template: None,
             priority: None,
+            tags: None,
+            allow_empty_body: false,
+            sequential_tabstops: false,
         }
     }
 }
+
+/// Creates a builder from the `(prefix, body)` pair
+impl From<(&str, Vec<&str>)> for SnippetBuilder {
+    fn from((prefix, body): (&str, Vec<&str>)) -> Self {
+        Self::new()
+            .set_prefix(prefix)
+            .set_body(body)
+    }
+}
+
+impl From<Snippet> for SnippetBuilder {
+    fn from(value: Snippet) -> Self {
+        // the snippets read from the files may be prefix-only:
+        let allow_empty_body = value.body.is_empty();
+
+        Self {
+            name: value.name,
+            prefix: value.prefix,
+            body: value.body,
+            description: value.description,
+            scope: value.scope,
+            is_file_template: value.is_file_template,
+            priority: value.priority,
+            tags: value.tags,
+            allow_empty_body,
+            sequential_tabstops: false,
+        }
+    }
+}
+
+/// __BONUS__: The builder methods for Rust snippets (use crate option `features = ["rust"]`)
+#[cfg(feature = "rust")]
+impl SnippetBuilder {
+    /// `[rust]`: Prepends a rustdoc comment block (`/// ...` lines) to the body
+    pub fn with_rustdoc(mut self, lines: &[&str]) -> Self {
+        let doc = lines.iter().map(|line| format!("/// {line}").trim_end().to_owned());
+        self.body.splice(0..0, doc);
+        self
+    }
+
+    /// `[rust]`: Comments out the non-empty body lines with `//` (see `comment_out()`)
+    pub fn comment_out_rust(self) -> Self {
+        self.comment_out("//")
+    }
+}
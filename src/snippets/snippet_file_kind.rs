@@ -0,0 +1,29 @@
+use std::path::Path;
+
+/// The kind of a VS Code snippets file (see `SnippetsFile::write_to_typed()`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnippetFileKind {
+    /// The global snippets file (`*.code-snippets`), the snippets are scoped by their `scope` field
+    Global,
+    /// The language snippets file (`{language}.json`), all the snippets belong to the file language
+    Language,
+}
+
+impl SnippetFileKind {
+    /// Returns the file extension of the kind (without the dot)
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Global => "code-snippets",
+            Self::Language => "json",
+        }
+    }
+
+    /// Detects the kind from the file extension (`None` for the other extensions)
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "code-snippets" => Some(Self::Global),
+            "json" => Some(Self::Language),
+            _ => None,
+        }
+    }
+}
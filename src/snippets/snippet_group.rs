@@ -0,0 +1,74 @@
+use super::SnippetBuilder;
+
+/// # The Snippet Group
+///
+/// 👪 The shared defaults of a snippets family: the scope, the priority and the description
+/// prefix. The snippets made by the group inherit them unless they set their own values.
+///
+/// ## Usage
+/// ```rust
+/// # use vscode_generator::{ Snippet, SnippetsFile, snippets::SnippetGroup };
+/// let rust = SnippetGroup::new()
+///     .set_scope("rust")
+///     .set_description_prefix("Rust: ");
+///
+/// let file = SnippetsFile::new(vec![
+///     rust.snippet("fn", vec!["fn $1() {", "    $0", "}"]),
+///     rust.apply(Snippet::fn_alias(".clone", ".clone").set_description("clone")),
+///     rust.snippet("dep", vec!["$1 = \"$2\""]).set_scope("toml"),
+/// ]);
+///
+/// let scopes: Vec<_> = file.snippets.values().map(|snip| snip.scope().unwrap()).collect();
+/// assert_eq!(scopes, vec!["rust", "rust", "toml"]);
+/// assert_eq!(file.snippets[1].description(), Some("Rust: clone"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SnippetGroup {
+    /// The default language scope
+    pub scope: Option<String>,
+    /// The default priority
+    pub priority: Option<u32>,
+    /// The text prepended to the snippet descriptions
+    pub description_prefix: Option<String>,
+}
+
+impl SnippetGroup {
+    /// Creates a new group without defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the default scope
+    pub fn set_scope<S: Into<String>>(mut self, scope: S) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    /// Sets the default priority
+    pub fn set_priority(mut self, priority: u32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Sets the text prepended to the snippet descriptions
+    pub fn set_description_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.description_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Creates a snippet builder with the group defaults (override them with the builder setters)
+    pub fn snippet<S: Into<String>>(&self, prefix: S, body: impl IntoIterator<Item = S>) -> SnippetBuilder {
+        self.apply(
+            SnippetBuilder::new()
+                .set_prefix(prefix)
+                .set_body(body.into_iter().map(Into::into).collect())
+        )
+    }
+
+    /// Applies the group defaults to the builder (the values set on the builder are kept)
+    /// 
+    /// The description prefix is prepended to the current description, so call it after `set_description()`.
+    pub fn apply(&self, builder: SnippetBuilder) -> SnippetBuilder {
+        builder.with_group_defaults(self)
+    }
+}
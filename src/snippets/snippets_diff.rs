@@ -0,0 +1,44 @@
+/// # The Snippets Diff
+/// 
+/// 🔍 The difference between two snippets files, made by `SnippetsFile::diff()`.
+/// The snippets are matched by name (the JSON key), a matched snippet is modified if
+/// its prefix, body or description differs.
+/// 
+/// ## Usage
+/// 
+/// ```rust
+/// # use vscode_generator::{ Snippet, SnippetsFile };
+/// let old = SnippetsFile::new(vec![
+///     Snippet::builder().set_name("print").set_prefix("pr").add_line("print!($0);"),
+///     Snippet::builder().set_name("debug").set_prefix("dbg").add_line("dbg!($0);"),
+/// ]);
+/// let new = SnippetsFile::new(vec![
+///     Snippet::builder().set_name("print").set_prefix("pr").add_line("println!($0);"),
+///     Snippet::builder().set_name("todo").set_prefix("todo").add_line("todo!($0);"),
+/// ]);
+/// 
+/// let diff = old.diff(&new);
+/// assert_eq!(diff.to_string(), "1 added, 1 removed, 1 changed");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SnippetsDiff {
+    /// The names of the snippets missing in the old file
+    pub added: Vec<String>,
+    /// The names of the snippets missing in the new file
+    pub removed: Vec<String>,
+    /// The names of the changed snippets
+    pub modified: Vec<String>,
+}
+
+impl SnippetsDiff {
+    /// Checks if there are no changes
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+impl std::fmt::Display for SnippetsDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} added, {} removed, {} changed", self.added.len(), self.removed.len(), self.modified.len())
+    }
+}
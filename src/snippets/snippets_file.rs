@@ -1,7 +1,15 @@
 use crate::prelude::*;
 use super::*;
+use super::jsonc;
+use std::{ io, borrow::Cow, fmt::Write as _, time::SystemTime, path::PathBuf, collections::{ BTreeMap, HashMap, HashSet } };
+#[cfg(feature = "fs")]
 use std::{ fs, path::Path };
-use serde::Serialize;
+use indexmap::IndexMap;
+use serde::{ Serialize, Serializer as _, ser::SerializeMap };
+use serde_json::ser::{ Serializer, PrettyFormatter };
+
+/// The start of the generation header line (followed by the timestamp, see `with_generation_header()`)
+const GENERATION_HEADER: &str = "// generated by vscode-generator at ";
 
 /// # Snippets File Manager
 /// 
@@ -18,6 +26,7 @@ use serde::Serialize;
 /// 
 /// #### ✨ Creating a new snippets file:
 /// ```rust
+/// # use vscode_generator::{ Snippet, SnippetsFile };
 /// let file = SnippetsFile::new(vec![
 ///     Snippet::new("print", vec!["println!(\"$0\");"]),
 ///     Snippet::new("debug", vec!["dbg!($0);"])
@@ -26,7 +35,8 @@ use serde::Serialize;
 /// 
 /// #### ➕ Adding snippets dynamically:
 /// ```rust
-/// let mut file = SnippetsFile::new(vec![]);
+/// # use vscode_generator::{ Snippet, SnippetsFile };
+/// let mut file = SnippetsFile::new(Vec::<Snippet>::new());
 /// 
 /// // Add single snippet
 /// file.add_snippet(Snippet::new("log", vec!["log::info!(\"$0\")"]));
@@ -39,7 +49,10 @@ use serde::Serialize;
 /// ```
 /// 
 /// #### 💾 Saving to file:
-/// ```rust
+/// ```rust,no_run
+/// # use vscode_generator::{ Snippet, SnippetsFile };
+/// # #[cfg(feature = "fs")]
+/// # fn main() -> vscode_generator::Result<()> {
 /// let file = SnippetsFile::new(vec![
 ///     Snippet::new("test", vec![
 ///         "#[test]",
@@ -51,6 +64,9 @@ use serde::Serialize;
 /// 
 /// // Saves to VS Code snippets directory
 /// file.write_to("./snippets/rust.code-snippets")?;
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "fs"))] fn main() {}
 /// ```
 /// 
 /// ## 📋 File Format
@@ -68,6 +84,31 @@ use serde::Serialize;
 /// }
 /// ```
 /// 
+/// #### 📖 Reading from file:
+/// ```rust,no_run
+/// # use vscode_generator::{ Snippet, SnippetsFile };
+/// # #[cfg(feature = "fs")]
+/// # fn main() -> vscode_generator::Result<()> {
+/// let committed = SnippetsFile::read_from("./snippets/rust.code-snippets")?;
+/// let generated = SnippetsFile::new(vec![
+///     Snippet::builder().set_name("print").set_prefix("pr").add_line("println!($0);")
+/// ]);
+/// 
+/// println!("{}", committed.diff(&generated));
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "fs"))] fn main() {}
+/// ```
+/// 
+/// ## 🔢 Ordering
+/// 
+/// The snippets are stored in an order-preserving map, so they're serialized in
+/// the insertion order. Use `sort_by_priority()` to move the snippets with a higher
+/// `priority` to the top of the file (the relative order of equal priorities is kept,
+/// the snippets without priority go last). Adding snippets after sorting appends them
+/// to the end, so sort right before writing the file. With `set_serialize_priority(false)`
+/// the sorted order is kept, but the `priority` fields are omitted from the output.
+/// 
 /// ## ⚠️ Error Handling
 /// 
 /// The structure uses custom `Result` type for error handling:
@@ -82,58 +123,755 @@ use serde::Serialize;
 /// * 🍎 MacOS: `~/Library/Application Support/Code/User/snippets`
 /// * 🐧 Linux: `~/.config/Code/User/snippets`
 ///
+/// 🧭 Use `snippets::user_snippets_dir()` to get it for the current OS and VS Code variant (Insiders, VSCodium, Cursor).
+/// `write_to_user()` writes there directly (`resolve_write_path()` previews the path).
+/// The snippets shipped inside a repository are written by `write_to_project()`.
+///
 /// 💡 Or simply access it via VS Code:
 /// 1. Press `Ctrl/Cmd + Shift + P`
 /// 2. Type "Snippets: Configure User Snippets"
 /// 3. Select the language or create a new snippet file 
 #[derive(Debug, Clone, Serialize)]
 pub struct SnippetsFile {
-    pub snippets: HashMap<String, Snippet>,
+    pub snippets: IndexMap<String, Snippet>,
+    /// The source of the JSON keys (the snippet names by default)
+    #[serde(skip)]
+    pub key_strategy: KeyStrategy,
+    /// Whether the snippet priorities are written to the json (`true` by default)
+    #[serde(skip)]
+    pub serialize_priority: bool,
+    /// Whether the json starts with a `// generated by vscode-generator at <timestamp>` comment
+    #[serde(skip)]
+    pub generation_header: bool,
+    /// The serialized names of the snippet fields (the VS Code ones by default)
+    #[serde(skip)]
+    pub field_names: FieldNames,
+    /// Whether the snippet tags are written to the json (`false` by default, VS Code doesn't know them)
+    #[serde(skip)]
+    pub serialize_tags: bool,
+    /// Whether the added builders must have sequential tabstops (`false` by default)
+    #[serde(skip)]
+    pub sequential_tabstops: bool,
 }
 
 impl SnippetsFile {
     /// Creates a new snippets file controller
     pub fn new<Sn: Into<Snippet>>(snippets: impl IntoIterator<Item = Sn>) -> Self {
+        let mut file = Self::from_map(IndexMap::new());
+        file.add_snippets(snippets);
+        file
+    }
+
+    /// Creates a new snippets file controller, failing on the first invalid snippet instead of panicking
+    /// 
+    /// The error is `Error::SnippetBuild` with the prefix of the failed snippet.
+    pub fn try_new<Sn: TryIntoSnippet>(snippets: impl IntoIterator<Item = Sn>) -> Result<Self> {
+        let mut file = Self::from_map(IndexMap::new());
+        for snippet in snippets {
+            file.add_snippet(snippet.try_into_snippet()?);
+        }
+        Ok(file)
+    }
+
+    /// Creates the snippets file with the default options
+    fn from_map(snippets: IndexMap<String, Snippet>) -> Self {
         Self {
-            snippets: snippets
-                .into_iter()
-                .map(|snip| {
-                    let snip = snip.into();
-                    (snip.name.clone(), snip)
-                })
-                .collect()
+            snippets,
+            key_strategy: KeyStrategy::Name,
+            serialize_priority: true,
+            generation_header: false,
+            field_names: FieldNames::default(),
+            serialize_tags: false,
+            sequential_tabstops: false,
         }
     }
 
+    /// Sets the source of the top-level JSON keys (the snippet names by default)
+    /// 
+    /// With `KeyStrategy::Prefix` the file reads like a hand-written one. The taken keys
+    /// (e.g. the same prefix in different scopes) are disambiguated like in `add_snippet()`.
+    pub fn key_strategy(mut self, strategy: KeyStrategy) -> Self {
+        self.key_strategy = strategy;
+        self
+    }
+
+    /// Sets whether the snippet priorities are written to the json (`true` by default)
+    /// 
+    /// With `false` the priorities are kept in memory (so `sort_by_priority()` still orders
+    /// the snippets), but the `priority` field is omitted from the output.
+    pub fn set_serialize_priority(mut self, serialize: bool) -> Self {
+        self.serialize_priority = serialize;
+        self
+    }
+
+    /// Sets whether the json starts with a `// generated by vscode-generator at <timestamp>` comment
+    /// 
+    /// The header is written by `to_json()`, `to_json_with()`, `write_to_writer()` and the `write_to*()`
+    /// methods (not by the reproducible `to_json_canonical()` and `to_json_sorted()`). VS Code and
+    /// `read_from()` accept the comments, so the header is skipped on reading.
+    pub fn with_generation_header(mut self, header: bool) -> Self {
+        self.generation_header = header;
+        self
+    }
+
+    /// Overrides the serialized names of the `prefix`, `body`, `description` and `scope` fields
+    /// 
+    /// It's meant for the non-VS Code tools reusing the snippet shape: VS Code and `read_from()`
+    /// only understand the default names.
+    pub fn with_field_names(mut self, names: FieldNames) -> Self {
+        self.field_names = names;
+        self
+    }
+
+    /// Sets whether the snippet tags are written to the json as a `tags` array (`false` by default)
+    /// 
+    /// VS Code ignores the unknown fields, but may warn about them in the snippets editor.
+    pub fn with_tags(mut self, serialize: bool) -> Self {
+        self.serialize_tags = serialize;
+        self
+    }
+
+    /// Makes `add_builders()` and `try_add_snippet()` fail unless the snippet tabstops are exactly
+    /// `1..=max` with a single `$0` (see `SnippetBuilder::require_sequential_tabstops()`)
+    /// 
+    /// The infallible `add_snippet()` and `new()` don't check the tabstops.
+    pub fn require_sequential_tabstops(mut self, require: bool) -> Self {
+        self.sequential_tabstops = require;
+        self
+    }
+
+    /// Returns the generation header line (empty if disabled)
+    fn header(&self) -> String {
+        match self.generation_header {
+            true => format!("{GENERATION_HEADER}{}\n", utc_timestamp(SystemTime::now())),
+            false => String::new(),
+        }
+    }
+
+    /// Returns the snippets by their JSON keys (see `key_strategy()`) as they're serialized
+    fn keyed(&self) -> IndexMap<String, SerializedSnippet<'_>> {
+        let mut keyed = IndexMap::with_capacity(self.snippets.len());
+
+        for (name, snippet) in &self.snippets {
+            let key = self.key_strategy.key(name, snippet);
+            let key = match keyed.contains_key(key) {
+                false => key.to_owned(),
+                true => (2..).map(|n| format!("{key}_{n}")).find(|key| !keyed.contains_key(key)).unwrap(),
+            };
+            keyed.insert(key, SerializedSnippet { snippet, file: self });
+        }
+
+        keyed
+    }
+
     /// Adds a new snippet to the collection
+    /// 
+    /// If the snippet name is already taken, a disambiguating suffix is appended (`name_2`, `name_3`, ...).
     pub fn add_snippet<S: Into<Snippet>>(&mut self, snippet: S) {
+        let mut snippet = snippet.into();
+
+        if self.snippets.contains_key(&snippet.name) {
+            snippet.name = (2..)
+                .map(|n| format!("{}_{n}", snippet.name))
+                .find(|name| !self.snippets.contains_key(name))
+                .unwrap();
+        }
+
+        self.snippets.insert(snippet.name.clone(), snippet);
+    }
+
+    /// Adds a new snippet to the collection, failing if its name is already taken
+    pub fn try_add_snippet<S: Into<Snippet>>(&mut self, snippet: S) -> Result<()> {
         let snippet = snippet.into();
+        if self.snippets.contains_key(&snippet.name) {
+            return Err(Error::DuplicateName(snippet.name));
+        }
+        if self.sequential_tabstops {
+            syntax::validate_sequential_tabstops(&snippet.body)?;
+        }
+
         self.snippets.insert(snippet.name.clone(), snippet);
+        Ok(())
+    }
+
+    /// Renames the snippet (both its key and `Snippet::name`), keeping its position in the file
+    /// 
+    /// Fails with `Error::NameNotFound` if there's no `old_name` snippet, and with `Error::DuplicateName`
+    /// if the `new_name` is already taken. The name is the JSON key with the default `KeyStrategy::Name`.
+    pub fn rename(&mut self, old_name: &str, new_name: &str) -> Result<()> {
+        let Some(index) = self.snippets.get_index_of(old_name) else {
+            return Err(Error::NameNotFound(old_name.to_owned()));
+        };
+        if old_name == new_name {
+            return Ok(());
+        }
+        if self.snippets.contains_key(new_name) {
+            return Err(Error::DuplicateName(new_name.to_owned()));
+        }
+
+        let mut snippet = self.snippets.shift_remove_index(index).map(|(_, snippet)| snippet).unwrap();
+        snippet.name = new_name.to_owned();
+        self.snippets.shift_insert(index, new_name.to_owned(), snippet);
+
+        Ok(())
+    }
+
+    /// Inserts the snippet under the explicit key (like `HashMap::insert()`), returning the displaced one
+    /// 
+    /// The `Snippet::name` is kept as is, the key is used instead of it in the file and the lookups.
+    /// The displaced snippet keeps its position in the file.
+    pub fn insert(&mut self, key: impl Into<String>, snippet: Snippet) -> Option<Snippet> {
+        self.snippets.insert(key.into(), snippet)
     }
 
-    /// Adds a new snippets to the collection
+    /// Adds a new snippets to the collection (the taken names are disambiguated like in `add_snippet()`)
     pub fn add_snippets<S: Into<Snippet>>(&mut self, snippets: impl IntoIterator<Item = S>) {
-        self.snippets.extend(
-            snippets
-                .into_iter()
-                .map(|snip| {
-                    let snip = snip.into();
-                    (snip.name.clone(), snip)
-                })
-        );
+        for snippet in snippets {
+            self.add_snippet(snippet);
+        }
+    }
+
+    /// Builds and adds the snippets (the taken names are disambiguated like in `add_snippet()`)
+    /// 
+    /// Nothing is added if a builder fails, the error is `Error::SnippetBuild` with its prefix.
+    pub fn add_builders(&mut self, builders: impl IntoIterator<Item = SnippetBuilder>) -> Result<()> {
+        let snippets = builders
+            .into_iter()
+            .map(|builder| match self.sequential_tabstops {
+                true => builder.require_sequential_tabstops(),
+                false => builder,
+            })
+            .map(TryIntoSnippet::try_into_snippet)
+            .collect::<Result<Vec<_>>>()?;
+
+        self.add_snippets(snippets);
+        Ok(())
+    }
+
+    /// Returns a copy of the file with only the snippets having the tag (the options are kept)
+    pub fn filter_by_tag(&self, tag: &str) -> Self {
+        let mut file = self.clone();
+        file.snippets.retain(|_, snippet| snippet.has_tag(tag));
+        file
+    }
+
+    /// Converts the snippets into builders (in the insertion order) for the bulk editing
+    /// 
+    /// The edited builders can be collected back into a file (`collect::<SnippetsFile>()`) or passed to `try_new()`.
+    pub fn into_builders(self) -> impl Iterator<Item = SnippetBuilder> {
+        self.snippets.into_values().map(Snippet::into_builder)
+    }
+
+    /// Merges the `other` snippets into the collection, the taken names are resolved by the policy
+    pub fn merge(&mut self, other: SnippetsFile, policy: MergePolicy) {
+        for (name, snippet) in other.snippets {
+            match policy {
+                MergePolicy::KeepExisting => { self.snippets.entry(name).or_insert(snippet); }
+                MergePolicy::Overwrite => { self.snippets.insert(name, snippet); }
+                MergePolicy::KeepBoth => self.add_snippet(snippet),
+            }
+        }
+    }
+
+    /// Marks (or unmarks) all the snippets as file templates
+    /// 
+    /// The marked snippets must follow the builder file template rules (a description and the body
+    /// starting at column zero, see `SnippetBuilder::validate()`). Nothing is marked if a snippet
+    /// doesn't qualify, the error is `Error::SnippetBuild` with its prefix.
+    pub fn mark_all_file_templates(&mut self, is_template: bool) -> Result<()> {
+        if is_template {
+            for snippet in self.snippets.values() {
+                SnippetBuilder::validate_file_template(snippet.description.as_ref(), &snippet.body)
+                    .map_err(|e| Error::SnippetBuild { prefix: snippet.prefix.clone(), source: Box::new(e) })?;
+            }
+        }
+
+        for snippet in self.snippets.values_mut() {
+            snippet.is_file_template = Some(is_template);
+        }
+        Ok(())
+    }
+
+    /// Strips the trailing spaces and tabs of each body line of all the snippets
+    pub fn trim_all(&mut self) {
+        for snippet in self.snippets.values_mut() {
+            for line in &mut snippet.body {
+                line.truncate(line.trim_end_matches([' ', '\t']).len());
+            }
+        }
+    }
+
+    /// Removes the snippets duplicating an earlier one (same prefix, body, description and scope)
+    pub fn dedup(&mut self) {
+        let mut seen = HashSet::new();
+        self.snippets.retain(|_, snip| {
+            seen.insert((snip.prefix.clone(), snip.body.clone(), snip.description.clone(), snip.scope.clone()))
+        });
+    }
+
+    /// Keeps only the last snippet for each prefix (useful to clean up overlays)
+    pub fn dedup_by_prefix(&mut self) {
+        let last: HashMap<String, usize> = self.snippets
+            .values()
+            .enumerate()
+            .map(|(i, snip)| (snip.prefix.clone(), i))
+            .collect();
+
+        let mut i = 0;
+        self.snippets.retain(|_, snip| {
+            i += 1;
+            last[&snip.prefix] == i - 1
+        });
+    }
+
+    /// Sorts the snippets by priority (the highest first, the snippets without priority last)
+    pub fn sort_by_priority(&mut self) {
+        self.snippets.sort_by(|_, a, _, b| b.priority.cmp(&a.priority));
+    }
+
+    /// Parses the snippets from json string (the snippet names are taken from the keys)
+    /// 
+    /// The comments and trailing commas are allowed (like in the VS Code `.code-snippets` files).
+    pub fn from_json(json: &str) -> Result<Self> {
+        Self::from_jsonc(json, false)
+    }
+
+    /// Parses the snippets from json string with comments
+    /// 
+    /// With `comments_as_descriptions`, the comments right before a snippet key become its
+    /// description (if the snippet has no description).
+    pub fn from_jsonc(json: &str, comments_as_descriptions: bool) -> Result<Self> {
+        let (stripped, comments) = jsonc::strip(json);
+
+        let mut snippets: IndexMap<String, Snippet> = serde_json::from_str(&stripped)
+            .map_err(|e| Error::Parse {
+                offset: jsonc::byte_offset(json, e.line(), e.column()),
+                message: e.to_string(),
+            })?;
+
+        for (name, snippet) in snippets.iter_mut() {
+            snippet.name = name.clone();
+
+            // the files saved on Windows may keep `\r` in the body lines (the line ending is
+            // dropped like in a string body, the interior ones become `\n`):
+            for line in snippet.body.iter_mut().filter(|line| line.contains('\r')) {
+                let text = line.strip_suffix("\r\n").or_else(|| line.strip_suffix('\r')).unwrap_or(line);
+                *line = normalize_newlines(text);
+            }
+        }
+
+        if comments_as_descriptions {
+            for (key, offset) in jsonc::top_level_keys(&stripped) {
+                if let Some(snippet) = snippets.get_mut(&key).filter(|snip| snip.description.is_none()) {
+                    snippet.description = jsonc::leading_comment(json, &comments, offset).map(Description::Single);
+                }
+            }
+        }
+
+        Ok(Self::from_map(snippets))
+    }
+
+    /// `[csv]`: Parses the snippets from a CSV table with the `prefix,body,description,scope` columns
+    /// 
+    /// The header row names the columns (in any order), `prefix` and `body` are required, an optional
+    /// `name` column sets the snippet names (the prefixes are used otherwise). The multiline `body`
+    /// cells are split into the lines, the empty `description` and `scope` cells are skipped.
+    /// The tab-separated tables (TSV) are detected by the header row.
+    #[cfg(feature = "csv")]
+    pub fn from_csv_reader<R: io::Read>(mut reader: R) -> Result<Self> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text).map_err(Error::from)?;
+
+        let error = |offset, message: String| Error::Parse { offset, message };
+        let csv_error = |e: csv::Error| error(e.position().map_or(0, |pos| pos.byte() as usize), e.to_string());
+
+        let header = text.lines().next().unwrap_or_default();
+        let delimiter = if header.contains('\t') && !header.contains(',') { b'\t' } else { b',' };
+        let mut table = csv::ReaderBuilder::new().delimiter(delimiter).from_reader(text.as_bytes());
+
+        let headers = table.headers().map_err(csv_error)?.clone();
+        let column = |name: &str| headers.iter().position(|header| header.trim().eq_ignore_ascii_case(name));
+        let (prefix, body) = match (column("prefix"), column("body")) {
+            (Some(prefix), Some(body)) => (prefix, body),
+            _ => return Err(error(0, "the `prefix` and `body` columns are required".to_owned())),
+        };
+        let (name, description, scope) = (column("name"), column("description"), column("scope"));
+
+        let mut file = Self::new(Vec::<Snippet>::new());
+        for record in table.records() {
+            let record = record.map_err(csv_error)?;
+            let cell = |column: Option<usize>| column.and_then(|i| record.get(i)).filter(|cell| !cell.is_empty());
+
+            let prefix = cell(Some(prefix)).unwrap_or_default();
+            let snippet = SnippetBuilder::new()
+                .set_name(cell(name).unwrap_or(prefix))
+                .set_prefix(prefix)
+                .set_body_str(cell(Some(body)).unwrap_or_default())
+                .set_description_opt(cell(description))
+                .set_scope_opt(cell(scope));
+
+            file.add_snippet(snippet.try_into_snippet()?);
+        }
+
+        Ok(file)
+    }
+
+    /// `[fs]`: Reads the snippets from file path
+    /// 
+    /// The Windows line endings (`\r\n` and lone `\r`) are normalized to `\n`.
+    #[cfg(feature = "fs")]
+    pub fn read_from<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let json = fs::read_to_string(path).map_err(Error::from)?;
+        Self::from_json(&normalize_newlines(&json))
+    }
+
+    /// `[fs]`: Reads all the snippets files (`.json` and `.code-snippets`) of the directory by their file stem
+    /// 
+    /// The other entries (subdirectories, backups, etc.) are skipped.
+    #[cfg(feature = "fs")]
+    pub fn read_dir<P: AsRef<Path>>(dir: P) -> Result<HashMap<String, SnippetsFile>> {
+        let mut files = HashMap::new();
+
+        for entry in fs::read_dir(dir).map_err(Error::from)? {
+            let path = entry.map_err(Error::from)?.path();
+            let is_snippets = path.extension().is_some_and(|ext| ext == "json" || ext == "code-snippets");
+
+            if let (true, true, Some(stem)) = (path.is_file(), is_snippets, path.file_stem()) {
+                files.insert(stem.to_string_lossy().into_owned(), Self::read_from(&path)?);
+            }
+        }
+
+        Ok(files)
+    }
+
+    /// Lints all the snippets (see [`lint()`]), the lints are paired with the snippet names
+    pub fn lint_all(&self) -> Vec<(String, Lint)> {
+        self.snippets
+            .iter()
+            .flat_map(|(name, snippet)| lint(snippet).into_iter().map(move |lint| (name.clone(), lint)))
+            .collect()
+    }
+
+    /// Returns the names of the snippets whose prefixes compete with the language built-in snippets
+    /// 
+    /// The unscoped snippets and the snippets scoped to the language are checked (see [`builtin_prefixes()`]).
+    /// The scoped snippets are also reported by [`lint()`] as `LintKind::BuiltinPrefix`.
+    pub fn check_builtin_collisions(&self, language: &str) -> Vec<String> {
+        let builtin = builtin_prefixes(language);
+        let in_language = |snippet: &Snippet| match &snippet.scope {
+            Some(scope) => scope.split(',').any(|id| id.trim() == language),
+            None => true,
+        };
+
+        self.snippets
+            .values()
+            .filter(|snippet| in_language(snippet) && builtin.contains(&snippet.prefix.as_str()))
+            .map(|snippet| snippet.name.clone())
+            .collect()
+    }
+
+    /// `[schema]`: Validates the serialized snippets against the VS Code snippets JSON schema
+    /// 
+    /// Returns `Error::SchemaViolation` with the JSON pointer of the first failing value.
+    #[cfg(feature = "schema")]
+    pub fn validate_against_schema(&self) -> Result<()> {
+        super::schema::validate(&self.to_value()?)
+    }
+
+    /// Finds the pairs of snippets with the same prefix suggested in the same language
+    /// 
+    /// A pair conflicts if one of the snippets is unscoped (it competes in all languages)
+    /// or if their scope sets (`"rust,toml"`) overlap.
+    pub fn scope_conflicts(&self) -> Vec<ScopeConflict> {
+        let scopes = |snippet: &Snippet| -> Option<Vec<String>> {
+            snippet.scope.as_ref().map(|scope| scope.split(',').map(|id| id.trim().to_owned()).collect())
+        };
+
+        let snippets: Vec<_> = self.snippets.values().collect();
+        let mut conflicts = vec![];
+
+        for (i, first) in snippets.iter().enumerate() {
+            for second in snippets[i + 1..].iter().filter(|snip| snip.prefix == first.prefix) {
+                let kind = match (scopes(first), scopes(second)) {
+                    (Some(a), Some(b)) => {
+                        let shared: Vec<_> = a.into_iter().filter(|id| b.contains(id)).collect();
+                        if shared.is_empty() {
+                            continue;
+                        }
+                        ScopeConflictKind::Overlapping(shared)
+                    }
+                    _ => ScopeConflictKind::Unscoped,
+                };
+
+                conflicts.push(ScopeConflict {
+                    prefix: first.prefix.clone(),
+                    names: [first.name.clone(), second.name.clone()],
+                    kind,
+                });
+            }
+        }
+
+        conflicts
+    }
+
+    /// Compares the snippets with the `other` (newer) collection
+    /// 
+    /// The snippets are matched by name, so use stable names (`set_name()`) to compare the generated files.
+    pub fn diff(&self, other: &SnippetsFile) -> SnippetsDiff {
+        let mut diff = SnippetsDiff::default();
+
+        for (name, snippet) in &self.snippets {
+            match other.snippets.get(name) {
+                None => diff.removed.push(name.clone()),
+                Some(new) if new.prefix != snippet.prefix
+                    || new.body != snippet.body
+                    || new.description != snippet.description => diff.modified.push(name.clone()),
+                _ => {}
+            }
+        }
+
+        diff.added = other.snippets
+            .keys()
+            .filter(|name| !self.snippets.contains_key(*name))
+            .cloned()
+            .collect();
+
+        diff
     }
 
     /// Converts the snippets to json string
+    /// 
+    /// The snippets are serialized one by one, so a failure is reported as `Error::SnippetSerialize`
+    /// with the snippet name.
+    #[cfg(not(feature = "rayon"))]
     pub fn to_json(&self) -> Result<String> {
-        serde_json::to_string_pretty(&self.snippets).map_err(Error::from)
+        let keyed = self.keyed();
+        let mut buffer = self.header().into_bytes();
+        let mut serializer = Serializer::pretty(&mut buffer);
+        let mut map = serializer.serialize_map(Some(keyed.len())).map_err(Error::from)?;
+
+        for (key, snippet) in &keyed {
+            map.serialize_entry(key, snippet).map_err(|source| snippet.error(source))?;
+        }
+        map.end().map_err(Error::from)?;
+
+        Ok(String::from_utf8(buffer).expect("serde_json emits valid UTF-8"))
+    }
+
+    /// Converts the snippets to json string
+    /// 
+    /// The snippets are serialized one by one, so a failure is reported as `Error::SnippetSerialize`
+    /// with the snippet name.
+    /// 
+    /// `[rayon]`: The snippets are serialized in parallel and concatenated in the insertion order,
+    /// the output is identical to the sequential one.
+    #[cfg(feature = "rayon")]
+    pub fn to_json(&self) -> Result<String> {
+        use rayon::prelude::*;
+
+        let snippets: Vec<_> = self.keyed().into_iter().collect();
+        let entries = snippets
+            .par_iter()
+            .map(|(key, snippet)| serialize_entry(key, snippet))
+            .collect::<Result<Vec<_>>>()?;
+
+        if entries.is_empty() {
+            return Ok(self.header() + "{}");
+        }
+
+        // concatenate the entries into a pre-sized buffer (the values are indented one level deeper):
+        let size = entries.iter().map(|(key, value)| key.len() + value.len() * 5 / 4 + 8).sum();
+        let mut json = self.header().into_bytes();
+        json.reserve(size);
+        json.push(b'{');
+
+        for (i, (key, value)) in entries.iter().enumerate() {
+            json.extend_from_slice(if i == 0 { b"\n  " } else { b",\n  " });
+            json.extend_from_slice(key);
+            json.extend_from_slice(b": ");
+
+            for line in value.split_inclusive(|&b| b == b'\n') {
+                json.extend_from_slice(line);
+                if line.ends_with(b"\n") {
+                    json.extend_from_slice(b"  ");
+                }
+            }
+        }
+        json.extend_from_slice(b"\n}");
+
+        Ok(String::from_utf8(json).expect("serde_json emits valid UTF-8"))
+    }
+
+    /// Converts the snippets to json string indented with the given number of spaces (`to_json()` uses 2)
+    pub fn to_json_with(&self, indent: usize) -> Result<String> {
+        let indent = " ".repeat(indent);
+        let mut buffer = self.header().into_bytes();
+        let mut serializer = Serializer::with_formatter(&mut buffer, PrettyFormatter::with_indent(indent.as_bytes()));
+
+        self.keyed().serialize(&mut serializer).map_err(Error::from)?;
+
+        Ok(String::from_utf8(buffer).expect("serde_json emits valid UTF-8"))
+    }
+
+    /// Converts the snippets to json value (e.g. to embed them into a larger document)
+    /// 
+    /// The value holds the same keys as `to_json()`, but the `serde_json` objects are ordered by key
+    /// (unless its `preserve_order` feature is enabled) and there's no generation header.
+    pub fn to_value(&self) -> Result<serde_json::Value> {
+        serde_json::to_value(self.keyed()).map_err(Error::from)
+    }
+
+    /// Converts the snippets to a Markdown cheat-sheet (e.g. to commit it next to the snippets file)
+    /// 
+    /// The summary table (prefix, description, scope) is followed by a section for each snippet
+    /// with the body in a fenced code block. The snippets are listed by their JSON keys (see `key_strategy()`).
+    pub fn to_markdown(&self) -> String {
+        let keyed = self.keyed();
+        let cell = |text: &str| text.replace('|', "\\|").replace('\n', "<br>");
+        let mut md = String::from("| Prefix | Description | Scope |\n| --- | --- | --- |\n");
+
+        for serialized in keyed.values() {
+            let snippet = serialized.snippet;
+            let description = snippet.description.as_ref().map(ToString::to_string).unwrap_or_default();
+            let scope = snippet.scope.as_deref().unwrap_or_default();
+            writeln!(md, "| `{}` | {} | {} |", cell(&snippet.prefix), cell(&description), cell(scope)).unwrap();
+        }
+
+        for (key, serialized) in &keyed {
+            let snippet = serialized.snippet;
+            write!(md, "\n### {key}\n\n").unwrap();
+            if let Some(description) = &snippet.description {
+                write!(md, "{}\n\n", description.lines().join("  \n")).unwrap();
+            }
+
+            // the fence must be longer than any backticks run in the body:
+            let body = snippet.body.join("\n");
+            let longest = body.split(|ch| ch != '`').map(str::len).max().unwrap_or_default();
+            let fence = "`".repeat(longest.max(2) + 1);
+            let language = snippet.scope.as_deref().and_then(|scope| scope.split(',').next()).unwrap_or_default();
+            writeln!(md, "{fence}{}\n{body}\n{fence}", language.trim()).unwrap();
+        }
+
+        md
+    }
+
+    /// Converts the snippets to json string with the keys sorted (independent of the insertion order)
+    /// 
+    /// The output is reproducible, so it can be compared against a committed snapshot: the generated
+    /// names (see `SnippetBuilder::gen_name()`) are replaced with the prefix and scope (`fn@rust`),
+    /// and the same keys are disambiguated (`_2`, `_3`, ...) in order of the serialized snippets.
+    pub fn to_json_canonical(&self) -> Result<String> {
+        let mut entries = self.snippets
+            .iter()
+            .map(|(name, snippet)| {
+                let serialized = SerializedSnippet { snippet, file: self };
+                let json = serde_json::to_string(&serialized)?;
+                Ok((self.stable_key(name, snippet).into_owned(), json, serialized))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        entries.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+
+        let mut sorted = BTreeMap::new();
+        for (key, _, serialized) in entries {
+            let key = match sorted.contains_key(&key) {
+                false => key,
+                true => (2..).map(|n| format!("{key}_{n}")).find(|key| !sorted.contains_key(key)).unwrap(),
+            };
+            sorted.insert(key, serialized);
+        }
+
+        serde_json::to_string_pretty(&sorted).map_err(Error::from)
+    }
+
+    /// Returns the JSON key of the snippet (see `key_strategy()`) independent of the generated names
+    /// 
+    /// A generated name is replaced with the prefix and scope (`fn@rust`, or `fn` without a scope),
+    /// so the same snippets rebuilt in another run get the same key.
+    fn stable_key<'a>(&self, name: &'a str, snippet: &'a Snippet) -> Cow<'a, str> {
+        let key = self.key_strategy.key(name, snippet);
+        match (SnippetBuilder::is_generated_name(key), &snippet.scope) {
+            (false, _) => Cow::Borrowed(key),
+            (true, Some(scope)) => Cow::Owned(format!("{}@{scope}", snippet.prefix)),
+            (true, None) => Cow::Borrowed(&snippet.prefix),
+        }
+    }
+
+    /// Returns the hash of the canonical json (see `to_json_canonical()`)
+    /// 
+    /// The hash is FNV-1a, so it's stable across the runs, platforms and compiler versions.
+    /// The generated snippet names don't affect it, so the rebuilt snippets hash the same.
+    pub fn content_hash(&self) -> u64 {
+        // the string-keyed maps of plain values can't fail to serialize:
+        let json = self.to_json_canonical().expect("snippets serialize to json");
+
+        json.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+
+    /// Converts the snippets to json string with the keys in alphabetical order
+    /// 
+    /// The keys are the names or the prefixes (see `key_strategy()`), compared case-insensitively
+    /// (`"Fn"` next to `"fn"`, not before all the lowercase keys like in `to_json_canonical()`).
+    pub fn to_json_sorted(&self) -> Result<String> {
+        let mut keyed = self.keyed();
+        keyed.sort_by(|a, _, b, _| a.to_lowercase().cmp(&b.to_lowercase()).then_with(|| a.cmp(b)));
+
+        serde_json::to_string_pretty(&keyed).map_err(Error::from)
     }
     
-    /// Writes the snippets to file path
-    pub fn write_to(&self, path: &str) -> Result<()> {
-        let path = Path::new(path);
+    /// Writes the snippets as json to the writer (streamed, without building the whole string)
+    pub fn write_to_writer<W: io::Write>(&self, mut writer: W) -> Result<()> {
+        writer.write_all(self.header().as_bytes()).map_err(Error::from)?;
+        serde_json::to_writer_pretty(writer, &self.keyed()).map_err(Error::from)
+    }
+
+    /// Writes the snippets as json to the writer while consuming the iterator
+    /// 
+    /// Only one snippet (and the written keys) is held in memory at a time, so it suits the huge generated sets.
+    /// The repeated names are disambiguated like in `add_snippet()` (`name_2`, `name_3`, ...).
+    /// Returns the number of written snippets.
+    pub fn from_iter_streaming<W, S>(writer: W, snippets: impl IntoIterator<Item = S>) -> Result<usize>
+    where
+        W: io::Write,
+        S: Into<Snippet>,
+    {
+        let mut serializer = Serializer::pretty(writer);
+        let mut map = serializer.serialize_map(None).map_err(Error::from)?;
+        let mut keys = HashSet::new();
+
+        for snippet in snippets {
+            let snippet = snippet.into();
+            let key = match keys.contains(&snippet.name) {
+                false => snippet.name.clone(),
+                true => (2..).map(|n| format!("{}_{n}", snippet.name)).find(|key| !keys.contains(key)).unwrap(),
+            };
+            map.serialize_entry(&key, &snippet).map_err(Error::from)?;
+            keys.insert(key);
+        }
+
+        map.end().map_err(Error::from)?;
+        Ok(keys.len())
+    }
+
+    /// `[fs]`: Writes the snippets to file path
+    /// 
+    /// Returns `Error::PathIsDirectory` if the path is an existing directory or has no file name (`"snippets/"`).
+    #[cfg(feature = "fs")]
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.write_to_reported(path).map(|_| ())
+    }
+
+    /// `[fs]`: Writes the snippets to file path like `write_to()`, returning the summary (e.g. for the build logs)
+    #[cfg(feature = "fs")]
+    pub fn write_to_reported<P: AsRef<Path>>(&self, path: P) -> Result<WriteReport> {
+        let path = path.as_ref();
+        check_file_path(path)?;
 
         // creating the file dir:
-        if let Some(dir) = path.parent() {
+        let mut created_dirs = false;
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            created_dirs = !dir.exists();
             fs::create_dir_all(dir).map_err(Error::from)?;
         }
         
@@ -141,8 +879,284 @@ impl SnippetsFile {
         let json = self.to_json()?;
 
         // create the file:
-        fs::write(path, json).map_err(Error::from)?;
+        fs::write(path, &json).map_err(Error::from)?;
 
-        Ok(())
+        Ok(WriteReport {
+            path: path.to_owned(),
+            bytes: json.len(),
+            snippets: self.snippets.len(),
+            created_dirs,
+        })
+    }
+
+    /// Returns the path of the file in the user snippets folder of the VS Code variant (nothing is written)
+    /// 
+    /// It's the path `write_to_user()` writes to, so it can be shown (or confirmed) before writing.
+    pub fn resolve_write_path(&self, filename: &str, variant: VsCodeVariant) -> Result<PathBuf> {
+        Ok(user_snippets_dir(variant)?.join(filename))
+    }
+
+    /// `[fs]`: Writes the snippets to the user snippets folder of the VS Code variant (e.g. `"rust.json"`)
+    /// 
+    /// Returns the written path (see `resolve_write_path()`).
+    #[cfg(feature = "fs")]
+    pub fn write_to_user(&self, filename: &str, variant: VsCodeVariant) -> Result<PathBuf> {
+        let path = self.resolve_write_path(filename, variant)?;
+        self.write_to(&path)?;
+        Ok(path)
+    }
+
+    /// `[fs]`: Writes the snippets to the project snippets file `{workspace_root}/.vscode/{name}.code-snippets`
+    /// 
+    /// The project snippets are shared with everyone opening the workspace, VS Code only reads
+    /// the global `.code-snippets` kind there (the `.vscode` folder is created if needed).
+    #[cfg(feature = "fs")]
+    pub fn write_to_project<P: AsRef<Path>>(&self, workspace_root: P, name: &str) -> Result<()> {
+        let extension = SnippetFileKind::Global.extension();
+        let name = name.strip_suffix(&format!(".{extension}")).unwrap_or(name);
+
+        self.write_to(workspace_root.as_ref().join(".vscode").join(format!("{name}.{extension}")))
     }
+
+    /// `[fs]`: Writes the snippets to file path, checking the rules of the file kind
+    /// 
+    /// The path extension must match the kind (`.code-snippets` for `Global`, `.json` for `Language`),
+    /// other extensions are accepted. The `Language` files ignore the `scope` field, so the snippets
+    /// relying on multiple scopes (`"rust,toml"`) fail with `Error::MultiScopeInLanguageFile`.
+    #[cfg(feature = "fs")]
+    pub fn write_to_typed<P: AsRef<Path>>(&self, path: P, kind: SnippetFileKind) -> Result<()> {
+        let path = path.as_ref();
+        if SnippetFileKind::from_path(path).is_some_and(|detected| detected != kind) {
+            return Err(Error::FileKindMismatch(path.to_owned()));
+        }
+
+        if kind == SnippetFileKind::Language {
+            if let Some(snippet) = self.snippets.values().find(|snip| snip.scope.as_ref().is_some_and(|scope| scope.contains(','))) {
+                return Err(Error::MultiScopeInLanguageFile(snippet.name.clone()));
+            }
+        }
+
+        self.write_to(path)
+    }
+
+    /// `[fs]`: Adds the snippets to the file (if it exists) and writes it back atomically
+    /// 
+    /// The existing file is read like in `read_from()` (so its comments are dropped), then the
+    /// snippets are merged into it with the policy. The snippets are matched by their JSON keys
+    /// (see `key_strategy()`), the generated names by the prefix and scope, so appending the rebuilt
+    /// snippets again doesn't duplicate them. The result is written to a temporary file next to
+    /// the destination and renamed over it, so the file is never left half-written.
+    #[cfg(feature = "fs")]
+    pub fn append_to<P: AsRef<Path>>(&self, path: P, policy: MergePolicy) -> Result<()> {
+        let path = path.as_ref();
+        check_file_path(path)?;
+
+        let mut file = if path.is_file() { Self::read_from(path)? } else { Self::new(Vec::<Snippet>::new()) };
+        let existing: HashMap<String, String> = file.snippets
+            .iter()
+            .map(|(name, snippet)| (self.stable_key(name, snippet).into_owned(), name.clone()))
+            .collect();
+
+        for (name, snippet) in &self.snippets {
+            match (existing.get(self.stable_key(name, snippet).as_ref()), policy) {
+                (Some(_), MergePolicy::KeepExisting) => {}
+                (Some(name), MergePolicy::Overwrite) => {
+                    // the existing key is kept:
+                    let snippet = Snippet { name: name.clone(), ..snippet.clone() };
+                    file.snippets.insert(name.clone(), snippet);
+                }
+                _ => file.add_snippet(snippet.clone()),
+            }
+        }
+        file.key_strategy = self.key_strategy;
+        file.serialize_priority = self.serialize_priority;
+
+        // creating the file dir:
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(Error::from)?;
+        }
+
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        fs::write(&tmp, file.to_json()?).map_err(Error::from)?;
+        fs::rename(&tmp, path).map_err(Error::from)
+    }
+
+    /// `[fs]`: Writes the snippets to file path only if its content changed (see `content_hash()`)
+    /// 
+    /// Returns `true` if the file was written. A missing or unparsable file is always written,
+    /// a reordered but otherwise equal file is not (the hash ignores the order). The snippets rebuilt
+    /// in another run (with new generated names) aren't rewritten either.
+    /// 
+    /// The existing file is read with the field names of `self` and compared as written (with its priorities
+    /// and tags), so the changed serialization options and the added or removed generation header are detected.
+    #[cfg(feature = "fs")]
+    pub fn write_to_if_changed<P: AsRef<Path>>(&self, path: P) -> Result<bool> {
+        let path = path.as_ref();
+
+        let existing = path.is_file().then(|| fs::read_to_string(path).ok()).flatten();
+        let unchanged = existing.is_some_and(|json| {
+            json.starts_with(GENERATION_HEADER) == self.generation_header
+                && self.parse_with_options(&normalize_newlines(&json)).is_ok_and(|file| file.content_hash() == self.content_hash())
+        });
+        if unchanged {
+            return Ok(false);
+        }
+
+        self.write_to(path)?;
+        Ok(true)
+    }
+
+    /// Parses the json written with the field names of `self`, the file hashes like its written content
+    #[cfg(feature = "fs")]
+    fn parse_with_options(&self, json: &str) -> Result<Self> {
+        let mut file = match self.field_names == FieldNames::default() {
+            true => Self::from_json(json)?,
+            false => {
+                let (stripped, _) = jsonc::strip(json);
+                let mut snippets: IndexMap<String, serde_json::Map<String, serde_json::Value>> = serde_json::from_str(&stripped).map_err(Error::from)?;
+
+                let names = &self.field_names;
+                let renames = [(&names.prefix, "prefix"), (&names.body, "body"), (&names.description, "description"), (&names.scope, "scope")];
+                for fields in snippets.values_mut() {
+                    // all the custom fields are taken out first, so the swapped names don't overwrite each other:
+                    let values: Vec<_> = renames.iter().map(|(custom, _)| fields.remove(custom.as_str())).collect();
+                    for ((_, name), value) in renames.iter().zip(values) {
+                        if let Some(value) = value {
+                            fields.insert(name.to_string(), value);
+                        }
+                    }
+                }
+
+                Self::from_json(&serde_json::to_string(&snippets).map_err(Error::from)?)?
+            }
+        };
+
+        // the parsed priorities and tags are the written ones, so they're all serialized back:
+        file.key_strategy = self.key_strategy;
+        file.field_names = self.field_names.clone();
+        file.serialize_priority = true;
+        file.serialize_tags = true;
+        Ok(file)
+    }
+
+    /// `[fs]`: Writes the snippets to file path, copying the existing file to `{path}.bak` first
+    #[cfg(feature = "fs")]
+    pub fn write_to_with_backup<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.write_to_with(path, &WriteOptions::new().set_backup(BackupPolicy::Once))
+    }
+
+    /// `[fs]`: Writes the snippets to file path with the options (e.g. the backup policy)
+    #[cfg(feature = "fs")]
+    pub fn write_to_with<P: AsRef<Path>>(&self, path: P, options: &WriteOptions) -> Result<()> {
+        let path = path.as_ref();
+
+        // backup the existing file:
+        if let Some(backup) = options.backup.backup_path(path).filter(|_| path.is_file()) {
+            fs::copy(path, backup).map_err(Error::from)?;
+        }
+
+        self.write_to(path)
+    }
+}
+
+/// Serializes the json key and the pretty snippet value, the errors are wrapped with the snippet name
+#[cfg(feature = "rayon")]
+fn serialize_entry(key: &str, snippet: &SerializedSnippet) -> Result<(Vec<u8>, Vec<u8>)> {
+    let key = serde_json::to_vec(key).map_err(|source| snippet.error(source))?;
+    let value = serde_json::to_vec_pretty(snippet).map_err(|source| snippet.error(source))?;
+    Ok((key, value))
+}
+
+/// The snippet as it's written to the file (with the file field names and priority options)
+struct SerializedSnippet<'a> {
+    snippet: &'a Snippet,
+    file: &'a SnippetsFile,
+}
+
+impl SerializedSnippet<'_> {
+    /// Wraps the serialization error with the snippet name
+    fn error(&self, source: serde_json::Error) -> Error {
+        Error::SnippetSerialize { name: self.snippet.name.clone(), source }
+    }
+}
+
+impl Serialize for SerializedSnippet<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let (snippet, names) = (self.snippet, &self.file.field_names);
+        let mut map = serializer.serialize_map(None)?;
+
+        map.serialize_entry(&names.prefix, &snippet.prefix)?;
+        map.serialize_entry(&names.body, &snippet.body)?;
+        if let Some(description) = &snippet.description {
+            map.serialize_entry(&names.description, description)?;
+        }
+        if let Some(scope) = &snippet.scope {
+            map.serialize_entry(&names.scope, scope)?;
+        }
+        if let Some(is_file_template) = snippet.is_file_template {
+            map.serialize_entry("isFileTemplate", &is_file_template)?;
+        }
+        if let Some(priority) = snippet.priority.filter(|_| self.file.serialize_priority) {
+            map.serialize_entry("priority", &priority)?;
+        }
+        if let Some(tags) = snippet.tags.as_ref().filter(|_| self.file.serialize_tags) {
+            map.serialize_entry("tags", tags)?;
+        }
+
+        map.end()
+    }
+}
+
+/// The files are equal if they hold the same snippets by name, regardless of the insertion order
+/// (the serialization options like `key_strategy` aren't compared)
+impl PartialEq for SnippetsFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.snippets.len() == other.snippets.len()
+            && self.snippets.iter().all(|(name, snippet)| other.snippets.get(name) == Some(snippet))
+    }
+}
+
+impl Eq for SnippetsFile {}
+
+/// Collects the snippets like `SnippetsFile::new()` (panics on an invalid builder, see `try_new()`)
+impl<S: Into<Snippet>> FromIterator<S> for SnippetsFile {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// Checks that the path can be written as a file (it isn't a directory and has a file name)
+#[cfg(feature = "fs")]
+fn check_file_path(path: &Path) -> Result<()> {
+    let ends_with_separator = path.as_os_str().to_string_lossy().ends_with(std::path::is_separator);
+
+    match path.is_dir() || ends_with_separator || path.file_name().is_none() {
+        true => Err(Error::PathIsDirectory(path.to_owned())),
+        false => Ok(()),
+    }
+}
+
+/// Replaces the `\r\n` and lone `\r` line endings with `\n`
+fn normalize_newlines(text: &str) -> String {
+    text.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// Formats the time as a UTC ISO 8601 timestamp (`2024-05-17T09:30:00Z`)
+fn utc_timestamp(time: SystemTime) -> String {
+    let secs = time.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_secs() as i64;
+    let (days, secs) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+
+    // the civil date from the days since the epoch (H. Hinnant's algorithm):
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+
+    format!("{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z", secs / 3600, secs / 60 % 60, secs % 60)
 }
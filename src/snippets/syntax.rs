@@ -0,0 +1,513 @@
+use crate::prelude::*;
+
+/// The state of the placeholders scanner
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    /// Plain text (top level or inside a placeholder default)
+    Text,
+    /// Right after an unescaped `$`
+    Dollar,
+    /// Right after `${`
+    Open,
+    /// Reading the index of `${N...`
+    Index,
+    /// Reading the name of `${VAR...`
+    Variable,
+    /// Inside the options of `${N|...|}`
+    Choice,
+    /// After the closing `|` of a choice
+    ChoiceEnd,
+    /// Inside a `${N/regex/format/options}` transform (part number, `${` depth)
+    Transform(u8, usize),
+}
+
+/// Validates the `${` / `}` nesting of the snippet body
+///
+/// 🔎 Only the snippet-level syntax is tracked: literal braces and parentheses that
+/// are not part of a placeholder (e.g. `${1:foo(}` or `fn main() {`) are ignored.
+/// Escaped sequences (`\$`, `\}`, `\\`) are skipped too. The placeholders may be nested
+/// (`${1:foo ${2:bar}}`), each `${` is matched with its own `}`.
+///
+/// ## Errors
+///
+/// Returns [`Error::InvalidSnippetSyntax`] with the 1-based line and column of:
+/// - an unclosed `${` placeholder
+/// - a `${` not followed by a tabstop index or a variable name
+/// - an unexpected character after a tabstop index or a variable name
+/// - a choice that isn't closed with `|}`
+/// - a choice with less than two options or with an empty option (`${1|a,,b|}`)
+///
+/// ## Usage
+/// ```rust
+/// use vscode_generator::snippets::validate_placeholders;
+///
+/// let valid = vec!["fn ${1:name}(${2:args}) {".to_owned(), "    $0".to_owned(), "}".to_owned()];
+/// assert!(validate_placeholders(&valid).is_ok());
+///
+/// let invalid = vec!["let ${1:value = $0;".to_owned()];
+/// assert!(validate_placeholders(&invalid).is_err());
+/// ```
+pub fn validate_placeholders(body: &[String]) -> Result<()> {
+    let mut state = State::Text;
+    let mut opened: Vec<(usize, usize)> = vec![];
+    let mut escaped = false;
+    let mut prev = '\0';
+    // the number of choice options and if the current option is empty:
+    let mut choice = (0, true);
+
+    let error = |line: usize, column: usize, message: &str| Error::InvalidSnippetSyntax {
+        line,
+        column,
+        message: message.to_owned(),
+    };
+
+    for (line, column, ch) in positioned_chars(body) {
+        let prev = std::mem::replace(&mut prev, ch);
+
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        // the `$` may be followed by any text character:
+        if state == State::Dollar {
+            match ch {
+                '{' => {
+                    opened.push((line, column - 1));
+                    state = State::Open;
+                    continue;
+                }
+                '$' => continue,
+                _ => state = State::Text,
+            }
+        }
+
+        match state {
+            State::Text => match ch {
+                '\\' => escaped = true,
+                '$' => state = State::Dollar,
+                '}' => { opened.pop(); }
+                _ => {}
+            },
+            State::Open => match ch {
+                '0'..='9' => state = State::Index,
+                'a'..='z' | 'A'..='Z' | '_' => state = State::Variable,
+                _ => return Err(error(line, column, "expected a tabstop index or a variable name after `${`")),
+            },
+            State::Index | State::Variable => match ch {
+                '0'..='9' => {}
+                'a'..='z' | 'A'..='Z' | '_' if state == State::Variable => {}
+                '|' if state == State::Index => {
+                    choice = (0, true);
+                    state = State::Choice;
+                }
+                ':' => state = State::Text,
+                '/' => state = State::Transform(0, 0),
+                '}' => {
+                    opened.pop();
+                    state = State::Text;
+                }
+                _ => return Err(error(line, column, &format!("unexpected character `{}` in placeholder", ch.escape_default()))),
+            },
+            State::Choice => match ch {
+                ',' | '|' if choice.1 => return Err(error(line, column, "empty choice option")),
+                ',' => choice = (choice.0 + 1, true),
+                '|' if choice.0 == 0 => {
+                    let (line, column) = *opened.last().unwrap();
+                    return Err(error(line, column, "choice must have at least two options"));
+                }
+                '|' => state = State::ChoiceEnd,
+                '\\' => {
+                    escaped = true;
+                    choice.1 = false;
+                }
+                _ => choice.1 = false,
+            },
+            State::ChoiceEnd => match ch {
+                '}' => {
+                    opened.pop();
+                    state = State::Text;
+                }
+                _ => return Err(error(line, column, "choice must be closed with `|}`")),
+            },
+            State::Transform(part, depth) => match ch {
+                '\\' => escaped = true,
+                '/' if depth == 0 && part < 2 => state = State::Transform(part + 1, 0),
+                '{' if part == 1 && prev == '$' => state = State::Transform(part, depth + 1),
+                '}' if depth > 0 => state = State::Transform(part, depth - 1),
+                '}' if part == 2 => {
+                    opened.pop();
+                    state = State::Text;
+                }
+                _ => {}
+            },
+            State::Dollar => unreachable!(),
+        }
+    }
+
+    match opened.pop() {
+        Some((line, column)) => Err(error(line, column, "unclosed placeholder `${`")),
+        None => Ok(()),
+    }
+}
+
+/// Iterates over the body characters with their 1-based line and column (the lines are joined with a newline)
+fn positioned_chars(body: &[String]) -> impl Iterator<Item = (usize, usize, char)> + '_ {
+    body.iter()
+        .enumerate()
+        .flat_map(move |(n, text)| {
+            let separator = (n > 0).then(|| (n, body[n - 1].chars().count() + 1, '\n'));
+
+            separator.into_iter().chain(
+                text.chars()
+                    .enumerate()
+                    .map(move |(i, ch)| (n + 1, i + 1, ch))
+            )
+        })
+}
+
+/// Checks that the tabstops are exactly `1..=max` with a single `$0` (see `SnippetBuilder::require_sequential_tabstops()`)
+pub(crate) fn validate_sequential_tabstops(body: &[String]) -> Result<()> {
+    let mut tabstops = std::collections::BTreeSet::new();
+    let mut finals = 0;
+    walk(&parse(body), &mut |node| match node.index() {
+        Some(0) => finals += 1,
+        Some(n) => { tabstops.insert(n); }
+        None => {}
+    });
+
+    let error = |(line, column): (usize, usize), message: String| Error::InvalidSnippetSyntax { line, column, message };
+
+    if let Some(missing) = (1..).find(|n| !tabstops.contains(n)).filter(|n| tabstops.range(n..).next().is_some()) {
+        let next = tabstops.range(missing..).next().copied().unwrap();
+        let position = tabstop_positions(body, next).first().copied().unwrap_or((1, 1));
+        return Err(error(position, format!("the tabstop `${missing}` is missing before `${next}`")));
+    }
+    match finals {
+        0 => {
+            let end = (body.len().max(1), body.last().map_or(0, |line| line.chars().count()) + 1);
+            Err(error(end, "there is no final tabstop `$0`".to_owned()))
+        }
+        1 => Ok(()),
+        n => {
+            let position = tabstop_positions(body, 0).get(1).copied().unwrap_or((1, 1));
+            Err(error(position, format!("the final tabstop `$0` is defined {n} times")))
+        }
+    }
+}
+
+/// Returns the 1-based positions of the `$N` and `${N...}` tabstops with the index (the escaped ones are skipped)
+fn tabstop_positions(body: &[String], index: u32) -> Vec<(usize, usize)> {
+    let chars: Vec<_> = positioned_chars(body).collect();
+    let mut positions = vec![];
+    let mut escaped = false;
+
+    for (i, &(line, column, ch)) in chars.iter().enumerate() {
+        if std::mem::take(&mut escaped) {
+            continue;
+        }
+        match ch {
+            '\\' => escaped = true,
+            '$' => {
+                let start = i + 1 + usize::from(chars.get(i + 1).is_some_and(|(_, _, ch)| *ch == '{'));
+                let digits: String = chars[start..].iter().map(|(_, _, ch)| *ch).take_while(char::is_ascii_digit).collect();
+                if digits.parse() == Ok(index) {
+                    positions.push((line, column));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    positions
+}
+
+/// Escapes the snippet syntax characters (`\\`, `$`, `}`) of a literal text
+/// 
+/// ```rust
+/// # use vscode_generator::snippets::escape_text;
+/// assert_eq!(escape_text("${name}"), "\\${name\\}");
+/// ```
+pub fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if matches!(ch, '\\' | '$' | '}') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+
+    escaped
+}
+
+/// The status of the final tabstop (`$0`) in a snippet body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinalTabstop {
+    /// There's no `$0`, the cursor ends up at the end of the snippet
+    Missing,
+    /// There's exactly one `$0`
+    Single,
+    /// The `$0` is defined several times (the number of occurrences)
+    Duplicated(usize),
+}
+
+impl FinalTabstop {
+    /// Creates the status from the number of `$0` occurrences
+    pub(crate) fn from_count(count: usize) -> Self {
+        match count {
+            0 => Self::Missing,
+            1 => Self::Single,
+            n => Self::Duplicated(n),
+        }
+    }
+}
+
+/// The parsed element of a snippet body
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Node {
+    /// A literal text (unescaped)
+    Text(String),
+    /// A `$N` or `${N}` tabstop (also `${N/regex/format/}` transforms)
+    Tabstop(u32),
+    /// A `${N:default}` placeholder
+    Placeholder(u32, Vec<Node>),
+    /// A `${N|one,two|}` choice
+    Choice(u32, Vec<String>),
+    /// A `$VAR`, `${VAR}` or `${VAR:default}` variable
+    Variable(String, Vec<Node>),
+}
+
+impl Node {
+    /// Returns the tabstop index of the node (if any)
+    pub(crate) fn index(&self) -> Option<u32> {
+        match self {
+            Self::Tabstop(n) | Self::Placeholder(n, _) | Self::Choice(n, _) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Returns the nested nodes of the node
+    pub(crate) fn children(&self) -> &[Node] {
+        match self {
+            Self::Placeholder(_, nodes) | Self::Variable(_, nodes) => nodes,
+            _ => &[],
+        }
+    }
+}
+
+/// Visits all the nodes (including the nested ones) in order of appearance
+pub(crate) fn walk<'a>(nodes: &'a [Node], f: &mut impl FnMut(&'a Node)) {
+    for node in nodes {
+        f(node);
+        walk(node.children(), f);
+    }
+}
+
+/// Renders the nodes to a plain text
+/// 
+/// The `replace` callback can override the rendering of any node, by default:
+/// - tabstops are removed
+/// - placeholders and variables are replaced with their default text
+/// - choices are replaced with their first option
+pub(crate) fn render(nodes: &[Node], replace: &mut impl FnMut(&Node) -> Option<String>) -> String {
+    let mut output = String::new();
+
+    for node in nodes {
+        if let Some(text) = replace(node) {
+            output.push_str(&text);
+            continue;
+        }
+
+        match node {
+            Node::Text(text) => output.push_str(text),
+            Node::Tabstop(_) => {}
+            Node::Placeholder(_, nodes) | Node::Variable(_, nodes) => output.push_str(&render(nodes, replace)),
+            Node::Choice(_, options) => output.push_str(options.first().map(String::as_str).unwrap_or_default()),
+        }
+    }
+
+    output
+}
+
+/// Parses the snippet body (the lines are joined with a newline)
+///
+/// The parser is lenient like the VS Code one: a malformed construct is kept as a plain text.
+pub(crate) fn parse(body: &[String]) -> Vec<Node> {
+    let chars: Vec<char> = body.join("\n").chars().collect();
+    let mut parser = Parser { chars: &chars, pos: 0 };
+
+    parser.parse_nodes(false)
+}
+
+/// The recursive descent parser of the snippet syntax
+struct Parser<'a> {
+    chars: &'a [char],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    /// Returns the character at the given offset from the current position
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    /// Parses the nodes until the end of input (or the closing `}` of a placeholder)
+    fn parse_nodes(&mut self, nested: bool) -> Vec<Node> {
+        let mut nodes = vec![];
+        let mut text = String::new();
+
+        while let Some(ch) = self.peek(0) {
+            match ch {
+                '\\' if matches!(self.peek(1), Some('$' | '}' | '\\')) => {
+                    text.push(self.peek(1).unwrap());
+                    self.pos += 2;
+                    continue;
+                }
+                '}' if nested => break,
+                '$' => {
+                    let start = self.pos;
+                    if let Some(node) = self.parse_dollar() {
+                        if !text.is_empty() {
+                            nodes.push(Node::Text(std::mem::take(&mut text)));
+                        }
+                        nodes.push(node);
+                        continue;
+                    }
+                    self.pos = start;
+                }
+                _ => {}
+            }
+
+            text.push(ch);
+            self.pos += 1;
+        }
+
+        if !text.is_empty() {
+            nodes.push(Node::Text(text));
+        }
+
+        nodes
+    }
+
+    /// Parses an integer at the current position
+    fn parse_index(&mut self) -> Option<u32> {
+        let start = self.pos;
+        while self.peek(0).is_some_and(|ch| ch.is_ascii_digit()) {
+            self.pos += 1;
+        }
+
+        self.chars[start..self.pos].iter().collect::<String>().parse().ok()
+    }
+
+    /// Parses a variable name at the current position
+    fn parse_name(&mut self) -> Option<String> {
+        if !self.peek(0).is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '_') {
+            return None;
+        }
+
+        let start = self.pos;
+        while self.peek(0).is_some_and(|ch| ch.is_ascii_alphanumeric() || ch == '_') {
+            self.pos += 1;
+        }
+
+        Some(self.chars[start..self.pos].iter().collect())
+    }
+
+    /// Parses a `$...` construct (the current character is `$`)
+    fn parse_dollar(&mut self) -> Option<Node> {
+        self.pos += 1;
+
+        if self.peek(0) != Some('{') {
+            return match self.peek(0)? {
+                '0'..='9' => self.parse_index().map(Node::Tabstop),
+                _ => self.parse_name().map(|name| Node::Variable(name, vec![])),
+            };
+        }
+        self.pos += 1;
+
+        let (index, name) = match self.peek(0)? {
+            '0'..='9' => (self.parse_index(), None),
+            _ => (None, Some(self.parse_name()?)),
+        };
+
+        let node = match (self.peek(0)?, index, name) {
+            ('}', Some(n), _) => Node::Tabstop(n),
+            ('}', None, Some(name)) => Node::Variable(name, vec![]),
+            (':', index, name) => {
+                self.pos += 1;
+                let nodes = self.parse_nodes(true);
+                if self.peek(0) != Some('}') {
+                    return None;
+                }
+                match (index, name) {
+                    (Some(n), _) => Node::Placeholder(n, nodes),
+                    (None, Some(name)) => Node::Variable(name, nodes),
+                    _ => return None,
+                }
+            }
+            ('|', Some(n), _) => {
+                self.pos += 1;
+                Node::Choice(n, self.parse_choice()?)
+            }
+            ('/', index, name) => {
+                self.pos += 1;
+                self.skip_transform()?;
+                match (index, name) {
+                    (Some(n), _) => Node::Tabstop(n),
+                    (None, Some(name)) => Node::Variable(name, vec![]),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        // skip the closing brace:
+        self.pos += 1;
+        Some(node)
+    }
+
+    /// Parses the choice options until the closing `|}` (stops at `}`)
+    fn parse_choice(&mut self) -> Option<Vec<String>> {
+        let mut options = vec![];
+        let mut option = String::new();
+
+        loop {
+            match self.peek(0)? {
+                '\\' if matches!(self.peek(1), Some(',' | '|' | '\\' | '$' | '}')) => {
+                    option.push(self.peek(1).unwrap());
+                    self.pos += 1;
+                }
+                ',' => options.push(std::mem::take(&mut option)),
+                '|' if self.peek(1) == Some('}') => {
+                    options.push(option);
+                    self.pos += 1;
+                    return Some(options);
+                }
+                '|' => return None,
+                ch => option.push(ch),
+            }
+            self.pos += 1;
+        }
+    }
+
+    /// Skips the `regex/format/options` parts of a transform (stops at `}`)
+    fn skip_transform(&mut self) -> Option<()> {
+        let mut part = 0;
+        let mut depth = 0;
+
+        loop {
+            match self.peek(0)? {
+                '\\' => self.pos += 1,
+                '/' if depth == 0 && part < 2 => part += 1,
+                '$' if part == 1 && self.peek(1) == Some('{') => {
+                    depth += 1;
+                    self.pos += 1;
+                }
+                '}' if depth > 0 => depth -= 1,
+                '}' if part == 2 => return Some(()),
+                _ => {}
+            }
+            self.pos += 1;
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use super::escape_text;
+
+/// # The Tabstop
+///
+/// 🎯 A typed tabstop, rendered to the right snippet syntax (with the escaping)
+/// by `SnippetBuilder::add_tabstop()` or `to_string()`.
+///
+/// ## Usage
+/// ```rust
+/// # use vscode_generator::{ SnippetBuilder, snippets::TabStop };
+/// let snippet = SnippetBuilder::new()
+///     .set_prefix("let")
+///     .add_line("let ")
+///     .add_tabstop(TabStop::Placeholder(1, "name".to_owned()))
+///     .add_tabstop(TabStop::Choice(2, vec![": i32".to_owned(), ": u32".to_owned()]))
+///     .add_tabstop(TabStop::Final)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(snippet.body, vec!["let ${1:name}${2|: i32,: u32|}$0"]);
+/// ```
+///
+/// ## Rendering
+///
+/// - `Empty(1)` → `$1`, `Final` → `$0`
+/// - `Placeholder(1, "a}")` → `${1:a\}}`
+/// - `Choice(1, ["a", "b"])` → `${1|a,b|}`, a single option is rendered as a placeholder
+///   and no options as an empty tabstop (VS Code needs at least two choices)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TabStop {
+    /// The empty tabstop (`$1`)
+    Empty(u32),
+    /// The tabstop with a default text (`${1:text}`)
+    Placeholder(u32, String),
+    /// The tabstop with the options dropdown (`${1|one,two|}`)
+    Choice(u32, Vec<String>),
+    /// The final cursor position (`$0`)
+    Final,
+}
+
+impl std::fmt::Display for TabStop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty(n) => write!(f, "${n}"),
+            Self::Final => write!(f, "$0"),
+            Self::Placeholder(n, text) => write!(f, "${{{n}:{}}}", escape_text(text)),
+            Self::Choice(n, options) => match options.as_slice() {
+                [] => write!(f, "${n}"),
+                [text] => write!(f, "${{{n}:{}}}", escape_text(text)),
+                options => {
+                    let options: Vec<_> = options.iter().map(|option| escape_choice(option)).collect();
+                    write!(f, "${{{n}|{}|}}", options.join(","))
+                }
+            },
+        }
+    }
+}
+
+/// Escapes the choice option syntax characters (`\\`, `,`, `|`)
+fn escape_choice(option: &str) -> String {
+    let mut escaped = String::with_capacity(option.len());
+    for ch in option.chars() {
+        if matches!(ch, '\\' | ',' | '|') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+
+    escaped
+}
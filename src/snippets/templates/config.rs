@@ -0,0 +1,36 @@
+use crate::snippets::{ Snippet, SnippetBuilder };
+
+/// __BONUS__: The snippet templates for TOML and YAML configs (use crate option `features = ["config"]`)
+impl Snippet {
+    /// `[config]`: Creates a TOML table template
+    pub fn toml_table<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec!["[$1]"])
+            .set_scope("toml")
+    }
+
+    /// `[config]`: Creates a TOML key-value template
+    pub fn toml_kv<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec!["${1:key} = $0"])
+            .set_scope("toml")
+    }
+
+    /// `[config]`: Creates a YAML key-value template
+    pub fn yaml_kv<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec!["${1:key}: $0"])
+            .set_scope("yaml")
+    }
+
+    /// `[config]`: Creates a YAML list item template
+    pub fn yaml_list_item<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec!["- $0"])
+            .set_scope("yaml")
+    }
+}
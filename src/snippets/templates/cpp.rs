@@ -0,0 +1,62 @@
+use crate::snippets::{ Snippet, SnippetBuilder };
+
+/// __BONUS__: The snippet templates for C and C++ (use crate option `features = ["cpp"]`)
+impl Snippet {
+    /// `[cpp]`: Creates an `#include` directive template
+    pub fn cpp_include<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec!["#include <$1>"])
+            .set_scopes(["c", "cpp"])
+    }
+
+    /// `[cpp]`: Creates a `main` function template (marked as a file template)
+    pub fn cpp_main<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "int main(int argc, char *argv[]) {",
+                "    $0",
+                "    return 0;",
+                "}",
+            ])
+            .set_description("Program entry point")
+            .set_scopes(["c", "cpp"])
+            .set_is_file_template(true)
+    }
+
+    /// `[cpp]`: Creates a class template
+    pub fn cpp_class<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "class ${1:Name} {",
+                "public:",
+                "    $1($2);",
+                "    ~$1();",
+                "",
+                "private:",
+                "    $0",
+                "};",
+            ])
+            .set_scope("cpp")
+    }
+
+    /// `[cpp]`: Creates a struct template
+    pub fn c_struct<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "struct ${1:Name} {",
+                "    $0",
+                "};",
+            ])
+            .set_scopes(["c", "cpp"])
+    }
+
+    /// `[cpp]`: Creates various comment templates (TODO, NOTE, etc.)
+    pub fn cpp_todo_comment<S: Into<String>>(prefix: S, comment_name: &str, comment_type: Option<&str>) -> SnippetBuilder {
+        Self::todo_comment(prefix, comment_name, comment_type)
+            .set_scopes(["c", "cpp"])
+    }
+}
@@ -0,0 +1,80 @@
+use crate::snippets::{ Snippet, SnippetBuilder };
+
+/// __BONUS__: The snippet templates for Java and Kotlin (use crate option `features = ["jvm"]`)
+impl Snippet {
+    /// `[jvm]`: Creates a Java class template named after the file (marked as a file template)
+    pub fn java_class<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "public class ${TM_FILENAME_BASE} {",
+                "    $0",
+                "}",
+            ])
+            .set_description("Java class")
+            .set_scope("java")
+            .set_is_file_template(true)
+    }
+
+    /// `[jvm]`: Creates a Java `public static void main` template (marked as a file template)
+    pub fn java_main<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "public class ${TM_FILENAME_BASE} {",
+                "    public static void main(String[] args) {",
+                "        $0",
+                "    }",
+                "}",
+            ])
+            .set_description("Program entry point")
+            .set_scope("java")
+            .set_is_file_template(true)
+    }
+
+    /// `[jvm]`: Creates various Java comment templates (TODO, NOTE, etc.)
+    pub fn java_todo_comment<S: Into<String>>(prefix: S, comment_name: &str) -> SnippetBuilder {
+        Self::todo_comment(prefix, comment_name, Some("//"))
+            .set_scope("java")
+    }
+
+    /// `[jvm]`: Creates a Kotlin function template
+    pub fn kotlin_fun<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "fun ${1:name}($2) {",
+                "    $0",
+                "}",
+            ])
+            .set_scope("kotlin")
+    }
+
+    /// `[jvm]`: Creates a Kotlin data class template (marked as a file template)
+    pub fn kotlin_data_class<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "data class ${TM_FILENAME_BASE}(",
+                "    val $0",
+                ")",
+            ])
+            .set_description("Kotlin data class")
+            .set_scope("kotlin")
+            .set_is_file_template(true)
+    }
+
+    /// `[jvm]`: Creates a Kotlin `main` function template (marked as a file template)
+    pub fn kotlin_main<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "fun main(args: Array<String>) {",
+                "    $0",
+                "}",
+            ])
+            .set_description("Program entry point")
+            .set_scope("kotlin")
+            .set_is_file_template(true)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::snippets::{ Snippet, SnippetBuilder, escape_text };
+
+/// __BONUS__: The snippet templates for Markdown (use crate option `features = ["markdown"]`)
+impl Snippet {
+    /// `[markdown]`: Creates a link template
+    pub fn md_link<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec!["[$1]($2)"])
+            .set_scope("markdown")
+    }
+
+    /// `[markdown]`: Creates a fenced code block template with the language tabstop
+    /// 
+    /// The backticks of the default language are stripped (they aren't allowed in the fence info string).
+    pub fn md_code_block<S: Into<String>>(prefix: S, default_language: Option<&str>) -> SnippetBuilder {
+        let language = escape_text(&default_language.unwrap_or_default().replace('`', ""));
+
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                format!("```${{1:{language}}}"),
+                "$0".to_owned(),
+                "```".to_owned(),
+            ])
+            .set_scope("markdown")
+    }
+
+    /// `[markdown]`: Creates a two-column table template
+    pub fn md_table<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "| ${1:Column} | ${2:Column} |",
+                "| --- | --- |",
+                "| $3 | $0 |",
+            ])
+            .set_scope("markdown")
+    }
+
+    /// `[markdown]`: Creates a YAML front matter template titled after the file (marked as a file template)
+    pub fn md_frontmatter<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "---",
+                "title: ${TM_FILENAME_BASE}",
+                "---",
+                "",
+                "$0",
+            ])
+            .set_description("Markdown document with front matter")
+            .set_scope("markdown")
+            .set_is_file_template(true)
+    }
+}
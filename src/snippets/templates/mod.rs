@@ -0,0 +1,22 @@
+//! The language-specific snippet templates (each one is enabled by its own crate feature)
+
+#[cfg(feature = "web")]
+mod web;
+
+#[cfg(feature = "sql")]
+mod sql;
+
+#[cfg(feature = "shell")]
+mod shell;
+
+#[cfg(feature = "cpp")]
+mod cpp;
+
+#[cfg(feature = "jvm")]
+mod jvm;
+
+#[cfg(feature = "markdown")]
+mod markdown;
+
+#[cfg(feature = "config")]
+mod config;
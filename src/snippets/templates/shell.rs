@@ -0,0 +1,56 @@
+use crate::snippets::{ Snippet, SnippetBuilder };
+
+/// __BONUS__: The snippet templates for Shell/Bash scripts (use crate option `features = ["shell"]`)
+impl Snippet {
+    /// `[shell]`: Creates a bash shebang template (marked as a file template)
+    pub fn bash_shebang<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "#!/usr/bin/env bash",
+                "",
+                "$0",
+            ])
+            .set_description("Bash script")
+            .set_scope("shellscript")
+            .set_is_file_template(true)
+    }
+
+    /// `[shell]`: Creates an `if` statement template
+    pub fn bash_if<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "if [[ $1 ]]; then",
+                "    $0",
+                "fi",
+            ])
+            .set_scope("shellscript")
+    }
+
+    /// `[shell]`: Creates a `for` loop template
+    pub fn bash_for<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "for ${1:item} in ${2:items}; do",
+                "    $0",
+                "done",
+            ])
+            .set_scope("shellscript")
+    }
+
+    /// `[shell]`: Creates a function template
+    pub fn bash_function<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec!["$1() { $0 }"])
+            .set_scope("shellscript")
+    }
+
+    /// `[shell]`: Creates various comment templates (TODO, NOTE, etc.)
+    pub fn bash_todo_comment<S: Into<String>>(prefix: S, comment_name: &str) -> SnippetBuilder {
+        Self::todo_comment(prefix, comment_name, Some("#"))
+            .set_scope("shellscript")
+    }
+}
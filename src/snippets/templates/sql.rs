@@ -0,0 +1,39 @@
+use crate::snippets::{ Snippet, SnippetBuilder };
+
+/// __BONUS__: The snippet templates for SQL (use crate option `features = ["sql"]`)
+impl Snippet {
+    /// `[sql]`: Creates a `SELECT` query template
+    pub fn sql_select<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec!["SELECT $1 FROM $2 WHERE $0"])
+            .set_scope("sql")
+    }
+
+    /// `[sql]`: Creates an `INSERT` query template
+    pub fn sql_insert<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec!["INSERT INTO $1 ($2) VALUES ($0);"])
+            .set_scope("sql")
+    }
+
+    /// `[sql]`: Creates a `CREATE TABLE` query template
+    pub fn sql_create_table<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "CREATE TABLE ${1:name} (",
+                "    ${2:id} ${3:INTEGER PRIMARY KEY},",
+                "    $0",
+                ");",
+            ])
+            .set_scope("sql")
+    }
+
+    /// `[sql]`: Creates various comment templates (TODO, NOTE, etc.)
+    pub fn sql_todo_comment<S: Into<String>>(prefix: S, comment_name: &str, comment_type: Option<&str>) -> SnippetBuilder {
+        Self::todo_comment(prefix, comment_name, Some(comment_type.unwrap_or("--")))
+            .set_scope("sql")
+    }
+}
@@ -0,0 +1,70 @@
+use crate::snippets::{ Snippet, SnippetBuilder };
+
+/// __BONUS__: The snippet templates for HTML and CSS (use crate option `features = ["web"]`)
+impl Snippet {
+    /// `[web]`: Creates a paired HTML tag template
+    pub fn html_tag<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec!["<$1>$0</$1>"])
+            .set_scope("html")
+    }
+
+    /// `[web]`: Creates a HTML5 document template titled after the file (marked as a file template)
+    pub fn html5_boilerplate<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "<!DOCTYPE html>",
+                "<html lang=\"en\">",
+                "<head>",
+                "    <meta charset=\"UTF-8\">",
+                "    <meta name=\"viewport\" content=\"width=device-width, initial-scale=1.0\">",
+                "    <title>${TM_FILENAME_BASE}</title>",
+                "</head>",
+                "<body>",
+                "    $0",
+                "</body>",
+                "</html>",
+            ])
+            .set_description("HTML5 document")
+            .set_scope("html")
+            .set_is_file_template(true)
+    }
+
+    /// `[web]`: Creates various HTML comment templates (TODO, NOTE, etc.)
+    pub fn html_todo_comment<S: Into<String>>(prefix: S, comment_name: &str) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![format!("<!-- {comment_name}: ${{1:...}} -->")])
+            .set_scope("html")
+    }
+
+    /// `[web]`: Creates a CSS rule template
+    pub fn css_rule<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec!["$1 { $0 }"])
+            .set_scope("css")
+    }
+
+    /// `[web]`: Creates a CSS media query template
+    pub fn css_media<S: Into<String>>(prefix: S) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![
+                "@media ${1:screen and (max-width: 768px)} {",
+                "    $0",
+                "}",
+            ])
+            .set_scope("css")
+    }
+
+    /// `[web]`: Creates various CSS comment templates (TODO, NOTE, etc.)
+    pub fn css_todo_comment<S: Into<String>>(prefix: S, comment_name: &str) -> SnippetBuilder {
+        Self::builder()
+            .set_prefix(prefix)
+            .set_body(vec![format!("/* {comment_name}: ${{1:...}} */")])
+            .set_scope("css")
+    }
+}
@@ -0,0 +1,161 @@
+use super::escape_text;
+
+/// # The Snippet Variable
+///
+/// 🔤 A typed VS Code snippet variable, rendered as `$NAME` by `SnippetBuilder::add_variable()`
+/// or `to_string()` (see the VS Code [Variables](https://code.visualstudio.com/docs/editor/userdefinedsnippets#_variables)).
+///
+/// ## Usage
+/// ```rust
+/// # use vscode_generator::{ SnippetBuilder, snippets::Variable };
+/// let snippet = SnippetBuilder::new()
+///     .set_prefix("header")
+///     .add_line("")
+///     .add_variable(Variable::LineComment)
+///     .add_line(" ")
+///     .add_variable(Variable::RelativeFilepath)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(snippet.body, vec!["$LINE_COMMENT", " $RELATIVE_FILEPATH"]);
+/// assert_eq!(Variable::ClipBoard.with_default("none"), "${CLIPBOARD:none}");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Variable {
+    /// The currently selected text or the empty string (`TM_SELECTED_TEXT`)
+    SelectedText,
+    /// The contents of the current line (`TM_CURRENT_LINE`)
+    CurrentLine,
+    /// The contents of the word under cursor or the empty string (`TM_CURRENT_WORD`)
+    CurrentWord,
+    /// The zero-index based line number (`TM_LINE_INDEX`)
+    LineIndex,
+    /// The one-index based line number (`TM_LINE_NUMBER`)
+    LineNumber,
+    /// The filename of the current document (`TM_FILENAME`)
+    Filename,
+    /// The filename of the current document without its extensions (`TM_FILENAME_BASE`)
+    FilenameBase,
+    /// The directory of the current document (`TM_DIRECTORY`)
+    Directory,
+    /// The full file path of the current document (`TM_FILEPATH`)
+    Filepath,
+    /// The relative (to the opened workspace or folder) file path of the current document (`RELATIVE_FILEPATH`)
+    RelativeFilepath,
+    /// The contents of the clipboard (`CLIPBOARD`)
+    ClipBoard,
+    /// The name of the opened workspace or folder (`WORKSPACE_NAME`)
+    WorkspaceName,
+    /// The path of the opened workspace or folder (`WORKSPACE_FOLDER`)
+    WorkspaceFolder,
+    /// The zero-index based cursor number (`CURSOR_INDEX`)
+    CursorIndex,
+    /// The one-index based cursor number (`CURSOR_NUMBER`)
+    CursorNumber,
+
+    /// The current year (`CURRENT_YEAR`)
+    CurrentYear,
+    /// The current year's last two digits (`CURRENT_YEAR_SHORT`)
+    CurrentYearShort,
+    /// The month as two digits, e.g. `02` (`CURRENT_MONTH`)
+    CurrentMonth,
+    /// The full name of the month, e.g. `July` (`CURRENT_MONTH_NAME`)
+    CurrentMonthName,
+    /// The short name of the month, e.g. `Jul` (`CURRENT_MONTH_NAME_SHORT`)
+    CurrentMonthNameShort,
+    /// The day of the month as two digits, e.g. `08` (`CURRENT_DATE`)
+    CurrentDate,
+    /// The name of day, e.g. `Monday` (`CURRENT_DAY_NAME`)
+    CurrentDayName,
+    /// The short name of the day, e.g. `Mon` (`CURRENT_DAY_NAME_SHORT`)
+    CurrentDayNameShort,
+    /// The current hour in 24-hour clock format (`CURRENT_HOUR`)
+    CurrentHour,
+    /// The current minute as two digits (`CURRENT_MINUTE`)
+    CurrentMinute,
+    /// The current second as two digits (`CURRENT_SECOND`)
+    CurrentSecond,
+    /// The number of seconds since the Unix epoch (`CURRENT_SECONDS_UNIX`)
+    CurrentSecondsUnix,
+    /// The current UTC time zone offset, e.g. `+07:00` (`CURRENT_TIMEZONE_OFFSET`)
+    CurrentTimezoneOffset,
+
+    /// 6 random Base-10 digits (`RANDOM`)
+    Random,
+    /// 6 random Base-16 digits (`RANDOM_HEX`)
+    RandomHex,
+    /// A Version 4 UUID (`UUID`)
+    Uuid,
+
+    /// The block comment start of the document language, e.g. `/*` (`BLOCK_COMMENT_START`)
+    BlockCommentStart,
+    /// The block comment end of the document language, e.g. `*/` (`BLOCK_COMMENT_END`)
+    BlockCommentEnd,
+    /// The line comment of the document language, e.g. `//` (`LINE_COMMENT`)
+    LineComment,
+}
+
+impl Variable {
+    /// All the documented VS Code variables
+    pub const ALL: [Self; 34] = [
+        Self::SelectedText, Self::CurrentLine, Self::CurrentWord, Self::LineIndex, Self::LineNumber,
+        Self::Filename, Self::FilenameBase, Self::Directory, Self::Filepath, Self::RelativeFilepath,
+        Self::ClipBoard, Self::WorkspaceName, Self::WorkspaceFolder, Self::CursorIndex, Self::CursorNumber,
+        Self::CurrentYear, Self::CurrentYearShort, Self::CurrentMonth, Self::CurrentMonthName,
+        Self::CurrentMonthNameShort, Self::CurrentDate, Self::CurrentDayName, Self::CurrentDayNameShort,
+        Self::CurrentHour, Self::CurrentMinute, Self::CurrentSecond, Self::CurrentSecondsUnix,
+        Self::CurrentTimezoneOffset, Self::Random, Self::RandomHex, Self::Uuid,
+        Self::BlockCommentStart, Self::BlockCommentEnd, Self::LineComment,
+    ];
+
+    /// Returns the variable name (e.g. `"TM_FILENAME"`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SelectedText => "TM_SELECTED_TEXT",
+            Self::CurrentLine => "TM_CURRENT_LINE",
+            Self::CurrentWord => "TM_CURRENT_WORD",
+            Self::LineIndex => "TM_LINE_INDEX",
+            Self::LineNumber => "TM_LINE_NUMBER",
+            Self::Filename => "TM_FILENAME",
+            Self::FilenameBase => "TM_FILENAME_BASE",
+            Self::Directory => "TM_DIRECTORY",
+            Self::Filepath => "TM_FILEPATH",
+            Self::RelativeFilepath => "RELATIVE_FILEPATH",
+            Self::ClipBoard => "CLIPBOARD",
+            Self::WorkspaceName => "WORKSPACE_NAME",
+            Self::WorkspaceFolder => "WORKSPACE_FOLDER",
+            Self::CursorIndex => "CURSOR_INDEX",
+            Self::CursorNumber => "CURSOR_NUMBER",
+            Self::CurrentYear => "CURRENT_YEAR",
+            Self::CurrentYearShort => "CURRENT_YEAR_SHORT",
+            Self::CurrentMonth => "CURRENT_MONTH",
+            Self::CurrentMonthName => "CURRENT_MONTH_NAME",
+            Self::CurrentMonthNameShort => "CURRENT_MONTH_NAME_SHORT",
+            Self::CurrentDate => "CURRENT_DATE",
+            Self::CurrentDayName => "CURRENT_DAY_NAME",
+            Self::CurrentDayNameShort => "CURRENT_DAY_NAME_SHORT",
+            Self::CurrentHour => "CURRENT_HOUR",
+            Self::CurrentMinute => "CURRENT_MINUTE",
+            Self::CurrentSecond => "CURRENT_SECOND",
+            Self::CurrentSecondsUnix => "CURRENT_SECONDS_UNIX",
+            Self::CurrentTimezoneOffset => "CURRENT_TIMEZONE_OFFSET",
+            Self::Random => "RANDOM",
+            Self::RandomHex => "RANDOM_HEX",
+            Self::Uuid => "UUID",
+            Self::BlockCommentStart => "BLOCK_COMMENT_START",
+            Self::BlockCommentEnd => "BLOCK_COMMENT_END",
+            Self::LineComment => "LINE_COMMENT",
+        }
+    }
+
+    /// Renders the variable with a default text for the empty or unknown values (`${NAME:default}`)
+    pub fn with_default(&self, default: &str) -> String {
+        format!("${{{}:{}}}", self.name(), escape_text(default))
+    }
+}
+
+impl std::fmt::Display for Variable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "${}", self.name())
+    }
+}
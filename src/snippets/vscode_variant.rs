@@ -0,0 +1,86 @@
+use crate::prelude::*;
+use std::{ env, path::PathBuf };
+
+/// # The VS Code Variant
+///
+/// 🧭 The VS Code flavor whose user snippets folder is targeted by [`user_snippets_dir()`].
+/// The forks keep their settings in the same layout, only the app folder name differs.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use vscode_generator::{ Snippet, SnippetsFile, snippets::{ user_snippets_dir, VsCodeVariant } };
+/// # #[cfg(feature = "fs")]
+/// # fn main() -> vscode_generator::Result<()> {
+/// let variant = VsCodeVariant::detect().unwrap_or_default();
+/// let file = SnippetsFile::new(vec![Snippet::new("todo", vec!["// TODO: $0"])]);
+///
+/// file.write_to(user_snippets_dir(variant)?.join("rust.code-snippets"))?;
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "fs"))] fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum VsCodeVariant {
+    /// Visual Studio Code (`Code`)
+    #[default]
+    Stable,
+    /// Visual Studio Code - Insiders (`Code - Insiders`)
+    Insiders,
+    /// VSCodium (`VSCodium`)
+    Codium,
+    /// Cursor (`Cursor`)
+    Cursor,
+}
+
+impl VsCodeVariant {
+    /// All the supported variants
+    pub const ALL: [Self; 4] = [Self::Stable, Self::Insiders, Self::Codium, Self::Cursor];
+
+    /// Returns the app folder name of the variant (e.g. `"Code - Insiders"`)
+    pub fn dir_name(&self) -> &'static str {
+        match self {
+            Self::Stable => "Code",
+            Self::Insiders => "Code - Insiders",
+            Self::Codium => "VSCodium",
+            Self::Cursor => "Cursor",
+        }
+    }
+
+    /// Finds the first installed variant (the one whose user folder exists)
+    pub fn detect() -> Option<Self> {
+        Self::ALL.into_iter().find(|variant| {
+            user_snippets_dir(*variant)
+                .ok()
+                .and_then(|dir| dir.parent().map(|user| user.is_dir()))
+                .unwrap_or(false)
+        })
+    }
+}
+
+/// Returns the user snippets folder of the VS Code variant for the current OS
+///
+/// * 🗂️ Windows: `%APPDATA%/{variant}/User/snippets`
+/// * 🍎 MacOS: `~/Library/Application Support/{variant}/User/snippets`
+/// * 🐧 Linux: `$XDG_CONFIG_HOME/{variant}/User/snippets` (or `~/.config/...`)
+///
+/// ## Errors
+///
+/// Returns [`Error::UserDirNotFound`] if the required environment variable (`APPDATA` or `HOME`) isn't set.
+pub fn user_snippets_dir(variant: VsCodeVariant) -> Result<PathBuf> {
+    let var = |name: &str| env::var_os(name).filter(|value| !value.is_empty()).map(PathBuf::from);
+
+    let config_dir = if cfg!(windows) {
+        var("APPDATA").ok_or_else(|| Error::UserDirNotFound("APPDATA".to_owned()))?
+    } else if cfg!(target_os = "macos") {
+        var("HOME")
+            .ok_or_else(|| Error::UserDirNotFound("HOME".to_owned()))?
+            .join("Library/Application Support")
+    } else {
+        match var("XDG_CONFIG_HOME") {
+            Some(dir) => dir,
+            None => var("HOME").ok_or_else(|| Error::UserDirNotFound("HOME".to_owned()))?.join(".config"),
+        }
+    };
+
+    Ok(config_dir.join(variant.dir_name()).join("User").join("snippets"))
+}
@@ -0,0 +1,70 @@
+use std::{ path::{ Path, PathBuf }, time::SystemTime };
+
+/// The backup of an existing snippets file before it's overwritten
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BackupPolicy {
+    /// No backup, the existing file is overwritten
+    #[default]
+    None,
+    /// Copies the existing file to `{path}.bak` (the previous backup is replaced)
+    Once,
+    /// Copies the existing file to `{path}.{unix_millis}.bak` (the backups are kept)
+    Timestamped,
+}
+
+impl BackupPolicy {
+    /// Returns the backup path for the file (if the policy makes backups)
+    pub fn backup_path(&self, path: &Path) -> Option<PathBuf> {
+        let suffix = match self {
+            Self::None => return None,
+            Self::Once => ".bak".to_owned(),
+            Self::Timestamped => {
+                let millis = SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis();
+                format!(".{millis}.bak")
+            }
+        };
+
+        let mut path = path.as_os_str().to_owned();
+        path.push(suffix);
+        Some(path.into())
+    }
+}
+
+/// # The Write Options
+///
+/// ⚙️ The options of `SnippetsFile::write_to_with()`.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use vscode_generator::{ Snippet, SnippetsFile, snippets::{ BackupPolicy, WriteOptions } };
+/// # #[cfg(feature = "fs")]
+/// # fn main() -> vscode_generator::Result<()> {
+/// let file = SnippetsFile::new(vec![Snippet::new("todo", vec!["// TODO: $0"])]);
+/// let options = WriteOptions::new().set_backup(BackupPolicy::Timestamped);
+///
+/// file.write_to_with("./snippets/rust.code-snippets", &options)?;
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "fs"))] fn main() {}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WriteOptions {
+    /// The backup of the existing file
+    pub backup: BackupPolicy,
+}
+
+impl WriteOptions {
+    /// Creates the default options (no backup)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the backup policy
+    pub fn set_backup(mut self, backup: BackupPolicy) -> Self {
+        self.backup = backup;
+        self
+    }
+}
@@ -0,0 +1,20 @@
+use std::path::PathBuf;
+
+/// The summary of a written snippets file (see `SnippetsFile::write_to_reported()`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WriteReport {
+    /// The written file path
+    pub path: PathBuf,
+    /// The number of written bytes
+    pub bytes: usize,
+    /// The number of written snippets
+    pub snippets: usize,
+    /// Whether the missing parent directories were created
+    pub created_dirs: bool,
+}
+
+impl std::fmt::Display for WriteReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} snippets, {} bytes", self.path.display(), self.snippets, self.bytes)
+    }
+}
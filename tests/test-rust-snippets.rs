@@ -1,11 +1,12 @@
+#![cfg(all(feature = "rust", feature = "fs"))]
+
 extern crate vscode_generator;
-use vscode_generator::{ SnippetsFile, Snippet };
+use vscode_generator::{ SnippetsFile, Snippet, snippets::{ VsCodeVariant, user_snippets_dir } };
 
-#[test]
-#[cfg(feature = "rust")]
-fn rust_snippets() {
+/// Generates the Rust snippets
+fn rust_snippets_file() -> SnippetsFile {
     // generating snippets:
-    let snippets = SnippetsFile::new(vec![
+    let mut snippets = SnippetsFile::new(vec![
         // text:
         
         Snippet::rust_text("hello_world", r#"println!("Hello, world!")"#),
@@ -23,30 +24,38 @@ fn rust_snippets() {
         Snippet::rust_todo_comment("/[i]", "[ ]", None),
         Snippet::rust_todo_comment("/[x]", "[x]", None),
 
-        // function aliases:
-
-        Snippet::rust_fn_alias(".to_string", ".to_string"),
-        Snippet::rust_fn_alias(".to_owned", ".to_owned"),
-        Snippet::rust_fn_alias(".unwrap", ".unwrap"),
-        Snippet::rust_fn_alias(".expect", ".expect"),
-        Snippet::rust_fn_alias(".clone", ".clone"),
-        Snippet::rust_fn_alias(".collect", ".collect"),
-        Snippet::rust_fn_alias(".iter", ".iter"),
-        Snippet::rust_fn_alias(".into_iter", ".into_iter"),
-
-        // macro aliases:
-
-        Snippet::rust_macro_alias("println!", "println", None),
-        Snippet::rust_macro_alias("format!", "format", None),
-        Snippet::rust_macro_alias("dbg!", "dbg", None),
-        Snippet::rust_macro_alias("assert!", "assert", None),
-        Snippet::rust_macro_alias("assert_eq!", "assert_eq", None),
-        Snippet::rust_macro_alias("panic!", "panic", None),
-        Snippet::rust_macro_alias("vec!", "vec", None),
-
-        Snippet::rust_macro_alias("str!", "str", None),
-        Snippet::rust_macro_alias("re!", "re", None),
-        Snippet::rust_macro_alias("deq!", "deq", None),
+    ]);
+
+    // function aliases:
+
+    snippets.add_snippets(Snippet::rust_fn_aliases(&[
+        (".to_string", ".to_string"),
+        (".to_owned", ".to_owned"),
+        (".unwrap", ".unwrap"),
+        (".expect", ".expect"),
+        (".clone", ".clone"),
+        (".collect", ".collect"),
+        (".iter", ".iter"),
+        (".into_iter", ".into_iter"),
+    ]));
+
+    // macro aliases:
+
+    snippets.add_snippets(Snippet::rust_macro_aliases(&[
+        ("println!", "println"),
+        ("format!", "format"),
+        ("dbg!", "dbg"),
+        ("assert!", "assert"),
+        ("assert_eq!", "assert_eq"),
+        ("panic!", "panic"),
+        ("vec!", "vec"),
+
+        ("str!", "str"),
+        ("re!", "re"),
+        ("deq!", "deq"),
+    ]));
+
+    snippets.add_snippets(vec![
         Snippet::rust_macro_alias("map!", "map", Some((" {", "}"))),
         Snippet::rust_macro_alias("set!", "set", Some(("[", "]"))),
         Snippet::rust_macro_alias("list!", "list", Some(("[", "]"))),
@@ -57,6 +66,12 @@ fn rust_snippets() {
         Snippet::rust_attr("#cfg", "cfg", vec!["test", "debug_assertions", "feature", "target_os"]),
         Snippet::rust_attr("#allow", "allow", vec!["dead_code", "unused_variables", "unused_imports"]),
 
+        // selection wrappers:
+
+        Snippet::rust_wrap_unsafe("~unsafe"),
+        Snippet::rust_wrap_block("~if_let", "if let ${2:Some(${3:value})} = ${4:expr}"),
+        Snippet::rust_wrap_block("~loop", "loop"),
+
         // operators:
 
         Snippet::builder()
@@ -578,9 +593,63 @@ fn rust_snippets() {
             ])
     ]);
 
+    snippets
+}
+
+#[test]
+fn rust_snippets() {
+    let snippets = rust_snippets_file();
+
     // create a cnippets file:
     snippets.write_to("./snippets/rust.code-snippets").unwrap();
-    snippets.write_to("C:/Users/Admin/AppData/Roaming/Code/User/snippets/rust.code-snippets").unwrap();  // NOTE: Path to the vscode custom user snippets folder!!
+
+    // the user snippets folder is only resolved (see `rust_snippets_to_user()`):
+    let path = snippets.resolve_write_path("rust.code-snippets", VsCodeVariant::Stable).unwrap();
+    assert_eq!(path, user_snippets_dir(VsCodeVariant::Stable).unwrap().join("rust.code-snippets"));
 }
 
+#[test]
+#[ignore = "writes to the VS Code user snippets folder"]
+fn rust_snippets_to_user() {
+    rust_snippets_file().write_to_user("rust.code-snippets", VsCodeVariant::Stable).unwrap();  // NOTE: Path to the vscode custom user snippets folder!!
+}
 
+
+
+#[test]
+fn rust_from_signature() -> vscode_generator::Result<()> {
+    let body = |signature: &str| -> vscode_generator::Result<Vec<String>> {
+        Ok(Snippet::rust_from_signature(signature)?.build()?.body)
+    };
+
+    let snippet = Snippet::rust_from_signature("pub fn foo(a: i32, b: &str) -> bool;")?.build()?;
+    assert_eq!(snippet.prefix, "foo");
+    assert_eq!(snippet.body, vec!["pub fn foo(${1:a}: i32, ${2:b}: &str) -> bool {", "    $0", "}"]);
+
+    assert_eq!(
+        body("fn map<'a, T: Clone>(&'a self, f: impl Fn(T) -> T, items: Vec<(T, T)>,) -> &'a T where T: Eq")?[0],
+        "fn map<'a, T: Clone>(&'a self, ${1:f}: impl Fn(T) -> T, ${2:items}: Vec<(T, T)>) -> &'a T where T: Eq {"
+    );
+    assert_eq!(body("async fn run()")?[0], "async fn run() {");
+
+    assert!(Snippet::rust_from_signature("struct Foo").is_err());
+    assert!(Snippet::rust_from_signature("fn foo(a: i32").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn comment_out() -> vscode_generator::Result<()> {
+    let snippet = Snippet::builder()
+        .set_prefix("example")
+        .set_body(vec!["// Example:", "fn main() {", "    // already", "    run();", "", "}"])
+        .comment_out_rust()
+        .build()?;
+
+    assert_eq!(snippet.body, vec!["// Example:", "// fn main() {", "    // already", "//     run();", "", "// }"]);
+
+    let shell = Snippet::builder().set_prefix("sh").add_line("echo $0").comment_out("#").build()?;
+    assert_eq!(shell.body, vec!["# echo $0"]);
+
+    Ok(())
+}
@@ -1,6 +1,44 @@
 extern crate vscode_generator;
-use vscode_generator::{ prelude::*, Snippet, SnippetsFile };
+use vscode_generator::{ prelude::*, snippets::{ Description, KeyStrategy, user_snippets_dir, VsCodeVariant }, Snippet, SnippetsFile };
 
+/// The temporary test directory, removed before the test and on drop (even if the test fails)
+#[cfg(feature = "fs")]
+struct TempDir(std::path::PathBuf);
+
+#[cfg(feature = "fs")]
+impl TempDir {
+    /// Reserves the `vscode-generator-{name}-{pid}` directory in the system temp dir (it isn't created)
+    fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("vscode-generator-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        Self(dir)
+    }
+}
+
+#[cfg(feature = "fs")]
+impl std::ops::Deref for TempDir {
+    type Target = std::path::Path;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "fs")]
+impl AsRef<std::path::Path> for TempDir {
+    fn as_ref(&self) -> &std::path::Path {
+        &self.0
+    }
+}
+
+#[cfg(feature = "fs")]
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+#[cfg(feature = "fs")]
 #[test]
 fn test_snippets() -> Result<()> {
     // create snippets:
@@ -17,8 +55,853 @@ fn test_snippets() -> Result<()> {
     
     // save snippets to file:
     let snippets_file = SnippetsFile::new(vec![snippet]);
-    // snippets_file.write_to("./snippets/rust.code-snippets")?;
-    snippets_file.write_to("C:/Users/Admin/AppData/Roaming/Code/User/snippets/test.code-snippets")?;
+    let dir = TempDir::new("snippets");
+    snippets_file.write_to(dir.join("test.code-snippets"))?;
+    assert_eq!(SnippetsFile::read_from(dir.join("test.code-snippets"))?, snippets_file);
+
+    // the user snippets folder is only resolved (see `test_write_to_user()`):
+    let path = snippets_file.resolve_write_path("test.code-snippets", VsCodeVariant::Stable)?;
+    assert_eq!(path, user_snippets_dir(VsCodeVariant::Stable)?.join("test.code-snippets"));
+
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+#[test]
+#[ignore = "writes to the VS Code user snippets folder"]
+fn test_write_to_user() -> Result<()> {
+    let snippets_file = SnippetsFile::new(vec![Snippet::builder().set_prefix("fn").add_line("fn $1() {$0}").set_scope("rust")]);
+    let path = snippets_file.write_to_user("test.code-snippets", VsCodeVariant::Stable)?;
+    assert_eq!(SnippetsFile::read_from(path)?.snippets.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_file_templates() -> Result<()> {
+    let mut snippets_file = SnippetsFile::new(vec![
+        Snippet::builder().set_prefix("main").set_body(vec!["fn main() {", "    $0", "}"]).set_description("Binary crate"),
+        Snippet::builder().set_prefix("lib").set_body(vec!["//! ${1:Crate docs}", "", "$0"]).set_description("Library crate"),
+    ]);
+    assert!(!snippets_file.to_json()?.contains("isFileTemplate"));
+
+    snippets_file.mark_all_file_templates(true)?;
+    let json = snippets_file.to_json()?;
+    assert_eq!(json.matches("\"isFileTemplate\": true").count(), 2);
+
+    // the marked snippets rebuild with the same rules:
+    for builder in SnippetsFile::from_json(&json)?.into_builders() {
+        builder.build()?;
+    }
+
+    // nothing is marked if a snippet doesn't qualify:
+    snippets_file.add_snippet(Snippet::new("mod", vec!["", "    mod $0;"]));
+    snippets_file.mark_all_file_templates(false)?;
+    match snippets_file.mark_all_file_templates(true) {
+        Err(Error::SnippetBuild { prefix, source }) => {
+            assert_eq!(prefix, "mod");
+            assert!(matches!(*source, Error::DescriptionIsRequired));
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+    assert!(!snippets_file.to_json()?.contains("isFileTemplate\": true"));
+
+    Ok(())
+}
+
+#[test]
+fn test_dedup() {
+    let mut snippets_file = SnippetsFile::new(vec![
+        Snippet::new("print", vec!["print!($0);"]),
+        Snippet::new("print", vec!["print!($0);"]),
+        Snippet::new("print", vec!["println!($0);"]),
+        Snippet::new("debug", vec!["dbg!($0);"]),
+    ]);
+
+    snippets_file.dedup();
+    assert_eq!(snippets_file.snippets.len(), 3);
+
+    snippets_file.dedup_by_prefix();
+    let bodies: Vec<_> = snippets_file.snippets.values().map(|snip| snip.body[0].as_str()).collect();
+    assert_eq!(bodies, vec!["println!($0);", "dbg!($0);"]);
+}
+
+#[test]
+fn test_read_jsonc() -> Result<()> {
+    let json = r#"{
+        // Prints a line
+        "print": {
+            "prefix": "pr", /* the trigger */
+            "body": ["println!(\"// not a comment\");"],
+        },
+    }"#;
+
+    let snippets_file = SnippetsFile::from_jsonc(json, true)?;
+    let snippet = &snippets_file.snippets["print"];
+    assert_eq!(snippet.name, "print");
+    assert_eq!(snippet.body, vec!["println!(\"// not a comment\");"]);
+    assert_eq!(snippet.description, Some(Description::Single("Prints a line".to_owned())));
+
+    match SnippetsFile::from_json("{ \"print\": { \"prefix\": 1 } }") {
+        Err(Error::Parse { offset, .. }) => assert_eq!(offset, 23),
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_try_new() {
+    let builders = vec![
+        SnippetBuilder::new().set_prefix("ok").add_line("$0"),
+        SnippetBuilder::new().set_prefix("broken"),
+    ];
+
+    match SnippetsFile::try_new(builders) {
+        Err(Error::SnippetBuild { prefix, source }) => {
+            assert_eq!(prefix, "broken");
+            assert!(matches!(*source, Error::BodyIsEmpty));
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    let mut file = SnippetsFile::try_new(vec![Snippet::new("ok", vec!["$0"])]).unwrap();
+    assert_eq!(file.snippets.len(), 1);
+
+    assert!(file.add_builders(vec![SnippetBuilder::new().set_prefix("a").add_line("$0"), SnippetBuilder::new()]).is_err());
+    assert_eq!(file.snippets.len(), 1);
+
+    file.add_builders(vec![SnippetBuilder::new().set_prefix("a").add_line("$0")]).unwrap();
+    assert_eq!(file.snippets.len(), 2);
+}
+
+#[test]
+fn test_multiline_description() -> Result<()> {
+    let single = Snippet::builder().set_prefix("a").add_line("a").set_description("One line").build()?;
+    assert!(single.to_json()?.contains("\"description\": \"One line\""));
+
+    let multi = Snippet::builder()
+        .set_prefix("b")
+        .add_line("b")
+        .set_description("First")
+        .add_description_line("Second")
+        .build()?;
+    let json = multi.to_json()?;
+    assert!(json.contains("\"description\": [\n    \"First\",\n    \"Second\"\n  ]"));
+
+    let file = SnippetsFile::from_json(r#"{ "b": { "prefix": "b", "body": ["b"], "description": ["First", "Second"] } }"#)?;
+    assert_eq!(file.snippets["b"].description, multi.description);
+
+    Ok(())
+}
+
+#[test]
+fn test_user_snippets_dir() -> Result<()> {
+    for variant in VsCodeVariant::ALL {
+        let dir = user_snippets_dir(variant)?;
+        assert!(dir.ends_with(format!("{}/User/snippets", variant.dir_name())));
+    }
+
+    let file = SnippetsFile::new(Vec::<Snippet>::new());
+    let path = file.resolve_write_path("rust.json", VsCodeVariant::Insiders)?;
+    assert_eq!(path, user_snippets_dir(VsCodeVariant::Insiders)?.join("rust.json"));
+
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_write_backup() -> Result<()> {
+    let dir = TempDir::new("backup");
+    let path = dir.join("backup.code-snippets");
+
+    let old = SnippetsFile::new(vec![Snippet::new("old", vec!["old$0"])]);
+    let new = SnippetsFile::new(vec![Snippet::new("new", vec!["new$0"])]);
+
+    old.write_to_with_backup(&path)?;
+    assert!(!dir.join("backup.code-snippets.bak").exists());
+
+    new.write_to_with_backup(&path)?;
+    let backup = std::fs::read_to_string(dir.join("backup.code-snippets.bak"))?;
+    assert_eq!(backup, old.to_json()?);
+    assert_eq!(std::fs::read_to_string(&path)?, new.to_json()?);
+
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_append_to() -> Result<()> {
+    use vscode_generator::snippets::MergePolicy;
+
+    let dir = TempDir::new("append");
+    let path = dir.join("append.code-snippets");
+
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(&path, r#"{
+        // hand-written
+        "print": { "prefix": "pr", "body": ["print!($0);"] },
+    }"#)?;
+
+    let generated = SnippetsFile::new(vec![
+        Snippet::builder().set_name("print").set_prefix("pr").add_line("println!($0);"),
+        Snippet::builder().set_name("debug").set_prefix("dbg").add_line("dbg!($0);"),
+    ]);
+
+    generated.append_to(&path, MergePolicy::KeepExisting)?;
+    let file = SnippetsFile::read_from(&path)?;
+    assert_eq!(file.snippets.keys().collect::<Vec<_>>(), vec!["print", "debug"]);
+    assert_eq!(file.snippets["print"].body, vec!["print!($0);"]);
+
+    generated.append_to(&path, MergePolicy::KeepBoth)?;
+    let file = SnippetsFile::read_from(&path)?;
+    assert_eq!(file.snippets.keys().collect::<Vec<_>>(), vec!["print", "debug", "print_2", "debug_2"]);
+
+    Ok(())
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn test_validate_against_schema() -> Result<()> {
+    let file = SnippetsFile::new(vec![
+        Snippet::builder().set_prefix("fn").add_line("fn $1() {}").set_description("First").add_description_line("Second"),
+        Snippet::builder().set_prefix("main").add_line("fn main() {}").set_description("Main").set_is_file_template(true).set_priority(3),
+    ]);
+
+    file.validate_against_schema()
+}
+
+#[test]
+fn test_key_strategy() -> Result<()> {
+    let snippets = vec![
+        Snippet::builder().set_prefix("fn").add_line("fn $1() {}").set_scope("rust").set_description("Function"),
+        Snippet::builder().set_prefix("fn").add_line("function $1() {}").set_scope("javascript"),
+    ];
+
+    let by_prefix = SnippetsFile::new(snippets.clone()).key_strategy(KeyStrategy::Prefix);
+    let keys: Vec<_> = SnippetsFile::from_json(&by_prefix.to_json()?)?.snippets.into_keys().collect();
+    assert_eq!(keys, vec!["fn", "fn_2"]);
+
+    let by_description = SnippetsFile::new(snippets).key_strategy(KeyStrategy::Description);
+    let file = SnippetsFile::from_json(&by_description.to_json_canonical()?)?;
+    assert!(file.snippets.contains_key("Function"));
+    // the generated name fallback is replaced with the prefix and scope:
+    assert!(file.snippets.contains_key("fn@javascript"));
+
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_read_crlf() -> Result<()> {
+    let dir = TempDir::new("crlf");
+    let path = dir.join("crlf.code-snippets");
+    std::fs::create_dir_all(&dir)?;
+
+    let json = "{\r\n  // Prints a line\r\n  \"print\": {\r\n    \"prefix\": \"pr\",\r\n    \"body\": [\"println!($0);\\r\", \"// done\\r\\n\"]\r\n  }\r\n}\r\n";
+    std::fs::write(&path, json)?;
+
+    let file = SnippetsFile::read_from(&path)?;
+    let snippet = &file.snippets["print"];
+    assert!(snippet.body.iter().all(|line| !line.contains('\r')));
+    assert_eq!(snippet.body, vec!["println!($0);", "// done"]);
+
+    let interior = SnippetsFile::from_json(r#"{ "two": { "prefix": "two", "body": ["a\r\nb\r"] } }"#)?;
+    assert_eq!(interior.snippets["two"].body, vec!["a\nb"]);
+
+    let round_trip = SnippetsFile::from_json(&file.to_json()?)?;
+    assert_eq!(round_trip.snippets["print"].body, snippet.body);
+
+    Ok(())
+}
+
+#[test]
+fn test_from_tuples() -> Result<()> {
+    let file = SnippetsFile::new(vec![
+        ("fn", vec!["fn $1() {", "    $0", "}"]),
+        ("pr", vec!["println!($0);"]),
+    ]);
+    let prefixes: Vec<_> = file.snippets.values().map(|snip| snip.prefix.as_str()).collect();
+    assert_eq!(prefixes, vec!["fn", "pr"]);
+
+    assert!(SnippetsFile::try_new(vec![("empty", vec![])]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_file_template_rules() {
+    assert!(Snippet::file_template("main", "Binary crate", vec!["fn main() {", "    $0", "}"]).build().is_ok());
+
+    let unlabeled = Snippet::builder().set_prefix("main").add_line("fn main() {}").set_is_file_template(true);
+    assert!(matches!(unlabeled.build(), Err(Error::DescriptionIsRequired)));
+
+    let indented = Snippet::file_template("main", "Binary crate", vec!["    fn main() {}"]);
+    assert!(matches!(indented.build(), Err(Error::FileTemplateIndented)));
+
+    // the leading blank lines don't hide the indentation:
+    let blank_first = Snippet::file_template("main", "Binary crate", vec!["", "    fn main() {}"]);
+    assert!(matches!(blank_first.build(), Err(Error::FileTemplateIndented)));
+
+    // the lines after the first one may be indented:
+    assert!(Snippet::file_template("a", "A", vec!["fn a() {", "    let x;", "}$0"]).build().is_ok());
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_read_dir() -> Result<()> {
+    let dir = TempDir::new("dir");
+
+    SnippetsFile::new(vec![Snippet::new("fn", vec!["fn $1() {}"])]).write_to(dir.join("rust.json"))?;
+    SnippetsFile::new(vec![Snippet::new("todo", vec!["// TODO: $0"])]).write_to(dir.join("global.code-snippets"))?;
+    std::fs::write(dir.join("notes.txt"), "not snippets")?;
+    std::fs::create_dir_all(dir.join("nested.json"))?;
+
+    let files = SnippetsFile::read_dir(&dir)?;
+    let mut stems: Vec<_> = files.keys().cloned().collect();
+    stems.sort();
+    assert_eq!(stems, vec!["global", "rust"]);
+    assert_eq!(files["rust"].snippets.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_to_json_sorted() -> Result<()> {
+    let file = SnippetsFile::new(vec![
+        Snippet::new("match", vec!["match $1 {}"]),
+        Snippet::new("Zip", vec!["zip($1)"]),
+        Snippet::new("fn", vec!["fn $1() {}"]),
+    ]).key_strategy(KeyStrategy::Prefix);
+
+    let keys: Vec<_> = SnippetsFile::from_json(&file.to_json_sorted()?)?.snippets.into_keys().collect();
+    assert_eq!(keys, vec!["fn", "match", "Zip"]);
+
+    let keys: Vec<_> = SnippetsFile::from_json(&file.to_json()?)?.snippets.into_keys().collect();
+    assert_eq!(keys, vec!["match", "Zip", "fn"]);
+
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_write_to_if_changed() -> Result<()> {
+    let dir = TempDir::new("hash");
+    let path = dir.join("hash.code-snippets");
+
+    let file = SnippetsFile::new(vec![Snippet::builder().set_name("fn").set_prefix("fn").add_line("fn $1() {}")]);
+    assert!(file.write_to_if_changed(&path)?);
+    assert!(!file.write_to_if_changed(&path)?);
+
+    let changed = SnippetsFile::new(vec![Snippet::builder().set_name("fn").set_prefix("fn").add_line("fn $1() {\n}")]);
+    assert_ne!(changed.content_hash(), file.content_hash());
+    assert!(changed.write_to_if_changed(&path)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_serialize_priority() -> Result<()> {
+    let mut file = SnippetsFile::new(vec![
+        Snippet::builder().set_name("low").set_prefix("low").add_line("$0").set_priority(1),
+        Snippet::builder().set_name("high").set_prefix("high").add_line("$0").set_priority(5),
+    ]).set_serialize_priority(false);
+    file.sort_by_priority();
+
+    let json = file.to_json()?;
+    assert!(!json.contains("priority"));
+
+    let keys: Vec<_> = SnippetsFile::from_json(&json)?.snippets.into_keys().collect();
+    assert_eq!(keys, vec!["high", "low"]);
+    assert_eq!(file.snippets["high"].priority, Some(5));
+
+    Ok(())
+}
+
+#[test]
+fn test_generation_header() -> Result<()> {
+    let file = SnippetsFile::new(vec![Snippet::new("fn", vec!["fn $1() {}"])]).with_generation_header(true);
+
+    let json = file.to_json()?;
+    let header = json.lines().next().unwrap();
+    let timestamp = header.strip_prefix("// generated by vscode-generator at ").unwrap();
+    assert_eq!(timestamp.len(), "2024-05-17T09:30:00Z".len());
+    assert!(timestamp.ends_with('Z') && timestamp.as_bytes()[10] == b'T');
+
+    let parsed = SnippetsFile::from_jsonc(&json, true)?;
+    assert_eq!(parsed.snippets.len(), 1);
+    assert!(parsed.snippets.values().all(|snip| snip.description.is_none()));
+    assert!(!file.to_json_canonical()?.starts_with("//"));
+
+    Ok(())
+}
+
+#[test]
+fn test_to_value() -> Result<()> {
+    let snippet = Snippet::builder().set_name("print").set_prefix("pr").add_line("println!($0);").build()?;
+    assert_eq!(snippet.to_value()?, serde_json::json!({ "prefix": "pr", "body": ["println!($0);"] }));
+
+    let file = SnippetsFile::new(vec![snippet]).key_strategy(KeyStrategy::Prefix);
+    let value = file.to_value()?;
+    assert_eq!(value["pr"]["body"][0], "println!($0);");
+    assert_eq!(value, serde_json::from_str::<serde_json::Value>(&file.to_json()?)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_scope_from_extension() -> Result<()> {
+    let scope = |ext: &str| -> Result<Option<String>> {
+        Ok(Snippet::builder().set_prefix("x").add_line("$0").set_scope_from_extension(ext).build()?.scope)
+    };
+
+    assert_eq!(scope("rs")?.as_deref(), Some("rust"));
+    assert_eq!(scope(".PY")?.as_deref(), Some("python"));
+    assert_eq!(scope("tsx")?.as_deref(), Some("typescriptreact"));
+    assert_eq!(scope(".foo")?.as_deref(), Some("foo"));
+
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_write_to_directory() -> Result<()> {
+    let dir = TempDir::new("isdir");
+    std::fs::create_dir_all(&dir)?;
+
+    let file = SnippetsFile::new(vec![Snippet::new("fn", vec!["fn $1() {}"])]);
+    assert!(matches!(file.write_to(&dir), Err(Error::PathIsDirectory(path)) if path == *dir));
+    assert!(matches!(file.write_to(format!("{}/missing/", dir.display())), Err(Error::PathIsDirectory(_))));
+    assert!(!dir.join("missing").exists());
+
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_write_to_typed() -> Result<()> {
+    use vscode_generator::snippets::SnippetFileKind;
+
+    let dir = TempDir::new("typed");
+    let file = SnippetsFile::new(vec![
+        Snippet::builder().set_name("fn").set_prefix("fn").add_line("fn $1() {}").set_scopes(["rust", "toml"]),
+    ]);
+
+    assert!(matches!(file.write_to_typed(dir.join("rust.json"), SnippetFileKind::Global), Err(Error::FileKindMismatch(_))));
+    assert!(matches!(
+        file.write_to_typed(dir.join("rust.json"), SnippetFileKind::Language),
+        Err(Error::MultiScopeInLanguageFile(name)) if name == "fn"
+    ));
+
+    file.write_to_typed(dir.join("all.code-snippets"), SnippetFileKind::Global)?;
+    assert!(dir.join("all.code-snippets").is_file());
+
+    Ok(())
+}
+
+#[test]
+fn test_snippets_file_eq() {
+    let fn_snippet = Snippet::builder().set_name("fn").set_prefix("fn").add_line("fn $1() {}").build().unwrap();
+    let let_snippet = Snippet::builder().set_name("let").set_prefix("let").add_line("let $1 = $0;").build().unwrap();
+
+    let first = SnippetsFile::new(vec![fn_snippet.clone(), let_snippet.clone()]);
+    let second = SnippetsFile::new(vec![let_snippet.clone(), fn_snippet.clone()]).key_strategy(KeyStrategy::Prefix);
+    assert_eq!(first, second);
+
+    let changed = SnippetsFile::new(vec![fn_snippet.into_builder().set_scope("rust").build().unwrap(), let_snippet]);
+    assert_ne!(first, changed);
+}
+
+#[test]
+fn test_field_names() -> Result<()> {
+    use vscode_generator::snippets::FieldNames;
+
+    let snippet = Snippet::builder()
+        .set_name("log")
+        .set_prefix("log")
+        .add_line("console.log($0);")
+        .set_description("Log")
+        .set_scope("javascript")
+        .set_priority(2)
+        .build()?;
+
+    let file = SnippetsFile::new(vec![snippet.clone()]);
+    assert_eq!(file.to_value()?["log"], snippet.to_value()?);
+
+    let names = FieldNames::new().set_prefix("trigger").set_body("bodyLines").set_description("doc").set_scope("languages");
+    let value = file.with_field_names(names).to_value()?;
+    assert_eq!(value["log"], serde_json::json!({
+        "trigger": "log",
+        "bodyLines": ["console.log($0);"],
+        "doc": "Log",
+        "languages": "javascript",
+        "priority": 2,
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn test_allow_empty_body() -> Result<()> {
+    assert!(matches!(SnippetBuilder::new().set_prefix("pub").build(), Err(Error::BodyIsEmpty)));
+
+    let snippet = SnippetBuilder::new().set_prefix("pub").allow_empty_body(true).build()?;
+    assert!(snippet.body.is_empty());
+    assert_eq!(snippet.to_value()?["body"], serde_json::json!([]));
+    assert!(snippet.to_builder().build().is_ok());
+
+    Ok(())
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_from_csv_reader() -> Result<()> {
+    let csv = "prefix,body,description,scope\nlog,\"console.log($1);\n$0\",Log to console,javascript\nfn,fn $1() {},,\n";
+    let file = SnippetsFile::from_csv_reader(csv.as_bytes())?;
+
+    let log = &file.snippets["log"];
+    assert_eq!(log.body, vec!["console.log($1);", "$0"]);
+    assert_eq!(log.description.as_ref().map(ToString::to_string).as_deref(), Some("Log to console"));
+    assert_eq!(log.scope.as_deref(), Some("javascript"));
+    assert_eq!(file.snippets["fn"].scope, None);
+
+    let tsv = "name\tprefix\tbody\nmain\tfm\tfn main() {}\n";
+    assert_eq!(SnippetsFile::from_csv_reader(tsv.as_bytes())?.snippets["main"].prefix, "fm");
+
+    assert!(matches!(SnippetsFile::from_csv_reader("prefix,text\na,b\n".as_bytes()), Err(Error::Parse { .. })));
+    assert!(matches!(SnippetsFile::from_csv_reader("prefix,body\na,\n".as_bytes()), Err(Error::SnippetBuild { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn test_into_builders() {
+    let file = SnippetsFile::new(vec![
+        Snippet::builder().set_name("fn").set_prefix("fn").add_line("fn $1() {").add_line("$0").add_line("}"),
+        Snippet::builder().set_name("let").set_prefix("let").add_line("let $1 = $0;"),
+    ]);
+
+    let edited: SnippetsFile = file
+        .into_builders()
+        .map(|builder| builder.map_lines(|line| line.insert_str(0, "    ")))
+        .collect();
+
+    let names: Vec<_> = edited.snippets.keys().cloned().collect();
+    assert_eq!(names, vec!["fn", "let"]);
+    assert_eq!(edited.snippets["fn"].body, vec!["    fn $1() {", "    $0", "    }"]);
+}
+
+#[test]
+fn test_tags() -> Result<()> {
+    let file = SnippetsFile::new(vec![
+        Snippet::builder().set_name("spawn").set_prefix("spawn").add_line("tokio::spawn($0);").add_tag("async").add_tag("async"),
+        Snippet::builder().set_name("test").set_prefix("test").add_line("#[test]").set_tags(["testing", "async"]),
+        Snippet::builder().set_name("read").set_prefix("read").add_line("std::fs::read($0)?"),
+    ]);
+
+    assert_eq!(file.snippets["spawn"].tags(), ["async"]);
+    assert!(!file.to_json()?.contains("tags"));
+
+    let tagged = file.filter_by_tag("async").with_tags(true);
+    let names: Vec<_> = tagged.snippets.keys().cloned().collect();
+    assert_eq!(names, vec!["spawn", "test"]);
+
+    let parsed = SnippetsFile::from_json(&tagged.to_json()?)?;
+    assert_eq!(parsed.snippets["test"].tags(), ["testing", "async"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_to_json_per_snippet() -> Result<()> {
+    let file = SnippetsFile::new(vec![
+        Snippet::builder().set_name("fn").set_prefix("fn").add_line("fn $1() {").add_line("}").set_description("Line 1").add_description_line("Line 2"),
+        Snippet::builder().set_name("let").set_prefix("let").add_line("let $1 = \"\\n\";").set_priority(1),
+    ]).with_generation_header(true);
+
+    let json = file.to_json()?;
+    let (header, body) = json.split_once('\n').unwrap();
+    assert!(header.starts_with("//"));
+    assert_eq!(body, file.clone().with_generation_header(false).to_json_with(2)?);
+    assert_eq!(SnippetsFile::new(Vec::<Snippet>::new()).to_json()?, "{}");
+
+    Ok(())
+}
+
+#[test]
+fn test_fragments() -> Result<()> {
+    use vscode_generator::snippets::Fragment;
+
+    let harness = Fragment::new("harness", vec!["#[cfg(test)]", "mod tests {", "", "}"]);
+    let snippet = Snippet::builder()
+        .set_prefix("tests")
+        .add_fragment(&harness)
+        .add_fragment(&Fragment::new("use", vec!["use super::*;"]).indented("    "))
+        .build()?;
+
+    assert_eq!(snippet.body, vec!["#[cfg(test)]", "mod tests {", "", "}", "    use super::*;"]);
+    assert_eq!(harness.indented("  ").lines[2], "");
+
+    Ok(())
+}
+
+#[test]
+fn test_to_markdown() {
+    let file = SnippetsFile::new(vec![
+        Snippet::builder().set_name("fn").set_prefix("fn").add_line("fn $1() {").add_line("}").set_description("A | function").set_scope("rust"),
+        Snippet::builder().set_name("md").set_prefix("code").add_line("```$1").add_line("```"),
+    ]).key_strategy(KeyStrategy::Prefix);
+
+    let md = file.to_markdown();
+    assert!(md.starts_with("| Prefix | Description | Scope |\n| --- | --- | --- |\n| `fn` | A \\| function | rust |\n| `code` |  |  |\n"));
+    assert!(md.contains("\n### fn\n\nA | function\n\n```rust\nfn $1() {\n}\n```\n"));
+    assert!(md.contains("\n### code\n\n````\n```$1\n```\n````\n"));
+}
+
+#[test]
+fn test_body_template() -> Result<()> {
+    let snippet = Snippet::builder()
+        .set_prefix("assert")
+        .set_body_template("assert_eq!({{ left }}, {{right}}, \"{{left}} {{msg}}\");\r\n{{", &[("left", 1), ("right", 2)])
+        .build()?;
+
+    assert_eq!(snippet.body, vec!["assert_eq!(${1:left}, ${2:right}, \"$1 {{msg}}\");", "{{"]);
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_write_to_reported() -> Result<()> {
+    let dir = TempDir::new("report");
+    let path = dir.join("nested").join("report.code-snippets");
+
+    let file = SnippetsFile::new(vec![Snippet::new("fn", vec!["fn $1() {}"]), Snippet::new("let", vec!["let $1;"])]);
+
+    let report = file.write_to_reported(&path)?;
+    assert_eq!(report.path, path);
+    assert_eq!(report.bytes, std::fs::metadata(&path)?.len() as usize);
+    assert_eq!(report.snippets, 2);
+    assert!(report.created_dirs);
+
+    assert!(!file.write_to_reported(&path)?.created_dirs);
+
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_read_string_body() -> Result<()> {
+    let dir = TempDir::new("string-body");
+    let path = dir.join("third-party.code-snippets");
+
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(&path, r#"{
+        "main": { "prefix": "main", "body": "fn main() {\r\n    $0\n}" },
+        "line": { "prefix": "ln", "body": "println!($0);" }
+    }"#)?;
+
+    let file = SnippetsFile::read_from(&path)?;
+    assert_eq!(file.snippets["main"].body, vec!["fn main() {", "    $0", "}"]);
+    assert_eq!(file.snippets["line"].body, vec!["println!($0);"]);
+
+    // written back as the array of lines:
+    let round_trip = SnippetsFile::from_json(&file.to_json()?)?;
+    assert_eq!(round_trip, file);
+    assert!(file.to_json()?.contains(r#""body": [
+      "fn main() {","#));
+
+    Ok(())
+}
+
+#[test]
+fn test_rename() -> Result<()> {
+    let mut file = SnippetsFile::from_json(r#"{
+        "snippet_1_1_abcdef": { "prefix": "a", "body": ["a"] },
+        "snippet_1_2_ghijkl": { "prefix": "b", "body": ["b"] },
+        "c": { "prefix": "c", "body": ["c"] }
+    }"#)?;
+
+    file.rename("snippet_1_1_abcdef", "alpha")?;
+    file.rename("c", "c")?;
+    assert!(matches!(file.rename("missing", "x"), Err(Error::NameNotFound(name)) if name == "missing"));
+    assert!(matches!(file.rename("snippet_1_2_ghijkl", "c"), Err(Error::DuplicateName(name)) if name == "c"));
+
+    assert_eq!(file.snippets.keys().collect::<Vec<_>>(), vec!["alpha", "snippet_1_2_ghijkl", "c"]);
+    assert_eq!(file.snippets["alpha"].name, "alpha");
+    assert!(file.to_json()?.contains(r#""alpha": {"#));
+    Ok(())
+}
+
+#[test]
+fn test_insert_with_key() -> Result<()> {
+    let mut file = SnippetsFile::new(vec![Snippet::builder().set_name("first").set_prefix("a").add_line("a")]);
+
+    assert!(file.insert("print", Snippet::new("pr", vec!["print!($0);"])).is_none());
+    let displaced = file.insert("first", Snippet::new("b", vec!["b"]));
+    assert_eq!(displaced.map(|snippet| snippet.prefix), Some("a".to_owned()));
+
+    assert_eq!(file.snippets.keys().collect::<Vec<_>>(), vec!["first", "print"]);
+    assert_ne!(file.snippets["print"].name, "print");
+
+    let keys: Vec<_> = SnippetsFile::from_json(&file.to_json()?)?.snippets.into_keys().collect();
+    assert_eq!(keys, vec!["first", "print"]);
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_write_to_project() -> Result<()> {
+    let root = TempDir::new("project");
+
+    let file = SnippetsFile::new(vec![Snippet::new("todo", vec!["// TODO: $0"])]);
+    file.write_to_project(&root, "team")?;
+    file.write_to_project(&root, "other.code-snippets")?;
+
+    assert_eq!(SnippetsFile::read_from(root.join(".vscode/team.code-snippets"))?, file);
+    assert!(root.join(".vscode/other.code-snippets").is_file());
+
+    Ok(())
+}
+
+#[test]
+fn test_fan_out() {
+    let snippets = Snippet::builder()
+        .add_line("println!(\"{:?}\", $0);")
+        .set_description("Debug print")
+        .set_scope("rust")
+        .fan_out(&["pd", "", "dbgp", "print"]);
+
+    assert_eq!(snippets.iter().map(|snippet| snippet.prefix.as_str()).collect::<Vec<_>>(), vec!["pd", "dbgp", "print"]);
+    assert!(snippets.iter().all(|snippet| snippet.body == snippets[0].body && snippet.scope.as_deref() == Some("rust")));
+    assert_eq!(SnippetsFile::new(snippets).snippets.len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Body cannot be empty")]
+fn test_fan_out_invalid() {
+    Snippet::builder().fan_out(&["a"]);
+}
+
+#[test]
+fn test_canonical_json_is_reproducible() -> Result<()> {
+    let build = || SnippetsFile::new(vec![
+        Snippet::new("fn", vec!["fn $1() {}$0"]),
+        Snippet::new("log", vec!["log($0);"]),
+        Snippet::new("log", vec!["console.log($0);"]),
+        Snippet::builder().set_prefix("fn").add_line("fn $0").set_scope("rust").build().unwrap(),
+    ]);
+
+    let mut reordered = build();
+    reordered.snippets.reverse();
+    let canonical = build().to_json_canonical()?;
+    assert_eq!(canonical, build().to_json_canonical()?);
+    assert_eq!(canonical, reordered.to_json_canonical()?);
+
+    let keys: Vec<_> = SnippetsFile::from_json(&canonical)?.snippets.into_keys().collect();
+    assert_eq!(keys, vec!["fn", "fn@rust", "log", "log_2"]);
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_write_to_if_changed_unnamed() -> Result<()> {
+    let dir = TempDir::new("hash-unnamed");
+    let path = dir.join("hash.code-snippets");
+
+    // every build generates new snippet names, like a rerun of the generator:
+    let build = || SnippetsFile::new(vec![
+        Snippet::new("fn", vec!["fn $1() {}$0"]),
+        Snippet::new("fn", vec!["function $1() {}$0"]),
+    ]);
+
+    assert_eq!(build().content_hash(), build().content_hash());
+    assert!(build().write_to_if_changed(&path)?);
+    assert!(!build().write_to_if_changed(&path)?);
+
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_write_to_if_changed_options() -> Result<()> {
+    use vscode_generator::snippets::FieldNames;
+
+    let dir = TempDir::new("hash-options");
+    let path = dir.join("hash.code-snippets");
+
+    let build = || SnippetsFile::new(vec![
+        Snippet::builder().set_prefix("log").add_line("console.log($0);").set_description("Log").set_priority(3).add_tag("debug"),
+        Snippet::builder().set_prefix("fn").add_line("function $1() {}$0").set_scope("javascript"),
+    ])
+        .with_tags(true)
+        .set_serialize_priority(false)
+        .with_field_names(FieldNames::new().set_prefix("body").set_body("prefix").set_scope("lang"));
+
+    assert!(build().write_to_if_changed(&path)?);
+    assert!(!build().write_to_if_changed(&path)?);
+
+    // the generation header is added, then removed:
+    assert!(build().with_generation_header(true).write_to_if_changed(&path)?);
+    assert!(!build().with_generation_header(true).write_to_if_changed(&path)?);
+    assert!(build().write_to_if_changed(&path)?);
+
+    // the tags and priorities are compared as written:
+    assert!(build().with_tags(false).write_to_if_changed(&path)?);
+    assert!(!build().with_tags(false).write_to_if_changed(&path)?);
+    assert!(build().with_tags(false).set_serialize_priority(true).write_to_if_changed(&path)?);
+
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_append_to_unnamed() -> Result<()> {
+    use vscode_generator::snippets::MergePolicy;
+
+    let dir = TempDir::new("append-unnamed");
+    let path = dir.join("append.code-snippets");
+
+    let build = |body: &str| SnippetsFile::new(vec![
+        Snippet::new("pr", vec![body]),
+        Snippet::builder().set_prefix("dbg").add_line("dbg!($0);").set_scope("rust").build().unwrap(),
+    ]);
+
+    build("print!($0);").append_to(&path, MergePolicy::KeepExisting)?;
+    build("println!($0);").append_to(&path, MergePolicy::KeepExisting)?;
+    let file = SnippetsFile::read_from(&path)?;
+    assert_eq!(file.snippets.len(), 2);
+    assert!(file.snippets.values().any(|snippet| snippet.body == vec!["print!($0);"]));
+
+    build("println!($0);").append_to(&path, MergePolicy::Overwrite)?;
+    let file = SnippetsFile::read_from(&path)?;
+    assert_eq!(file.snippets.len(), 2);
+    assert!(file.snippets.values().any(|snippet| snippet.body == vec!["println!($0);"]));
+
+    // the keys are the prefixes on both sides:
+    let by_prefix = build("println!($0);").key_strategy(KeyStrategy::Prefix);
+    by_prefix.append_to(&path, MergePolicy::KeepExisting)?;
+    by_prefix.append_to(&path, MergePolicy::KeepExisting)?;
+    let keys: Vec<_> = SnippetsFile::read_from(&path)?.snippets.into_keys().collect();
+    assert_eq!(keys, vec!["pr", "dbg"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_streaming() -> Result<()> {
+    let file = SnippetsFile::new(vec![
+        Snippet::builder().set_name("log").set_prefix("log").add_line("console.log($0);").set_scope("javascript"),
+        Snippet::builder().set_name("fn").set_prefix("fn").set_body(vec!["fn $1() {", "    $0", "}"]).set_description("Function"),
+    ]);
+    let mut json = Vec::new();
+    file.write_to_writer(&mut json)?;
+    assert_eq!(String::from_utf8(json).unwrap(), file.to_json()?);
+
+    let snippets = (0..3).map(|n| Snippet::builder().set_name("log").set_prefix("log").add_line(format!("console.log({n}, $0);")).build().unwrap());
+    let mut json = Vec::new();
+    assert_eq!(SnippetsFile::from_iter_streaming(&mut json, snippets)?, 3);
 
+    let parsed = SnippetsFile::from_json(std::str::from_utf8(&json).unwrap())?;
+    assert_eq!(parsed.snippets.keys().collect::<Vec<_>>(), vec!["log", "log_2", "log_3"]);
+    assert_eq!(parsed.snippets["log_3"].body, vec!["console.log(2, $0);"]);
     Ok(())
 }
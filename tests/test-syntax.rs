@@ -0,0 +1,278 @@
+extern crate vscode_generator;
+use vscode_generator::{ prelude::*, snippets::{ validate_placeholders, lint, LintKind, IndentStyle } };
+
+fn lines(body: &[&str]) -> Vec<String> {
+    body.iter().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn test_valid_placeholders() -> Result<()> {
+    validate_placeholders(&lines(&[
+        "fn ${1:foo(}(${2:args}) {",
+        "    ${3|one,two,three|} ${TM_FILENAME/(.*)\\..+$/${1:/upcase}/}",
+        "    \\${not_a_placeholder} ${4:outer ${5:inner}}",
+        "    ${0}",
+        "}",
+    ]))
+}
+
+#[test]
+fn test_invalid_placeholders() {
+    match validate_placeholders(&lines(&["fn main() {", "    let ${1:name = $0;"])) {
+        Err(Error::InvalidSnippetSyntax { line, column, .. }) => assert_eq!((line, column), (2, 9)),
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    assert!(validate_placeholders(&lines(&["${1|a,b}"])).is_err());
+    assert!(validate_placeholders(&lines(&["${ name }"])).is_err());
+}
+
+#[test]
+fn test_invalid_choices() {
+    use vscode_generator::Snippet;
+
+    assert!(validate_placeholders(&lines(&["${1|a,\\,b|}"])).is_ok());
+    assert!(Snippet::builder().set_prefix("a").add_line("${1|only|}").build_validated().is_err());
+    assert!(Snippet::new("b", vec!["${1|a,,b|}"]).validate_syntax().is_err());
+    assert!(Snippet::new("c", vec!["${1|a,b,|}"]).validate_syntax().is_err());
+}
+
+#[test]
+fn test_final_tabstop_status() {
+    use vscode_generator::{ Snippet, snippets::FinalTabstop };
+
+    assert_eq!(Snippet::new("a", vec!["fn $1() {", "    $0", "}"]).final_tabstop_status(), FinalTabstop::Single);
+    assert_eq!(Snippet::new("b", vec!["\\$0 ${1|$0,x|} $10"]).final_tabstop_status(), FinalTabstop::Missing);
+    assert_eq!(Snippet::new("c", vec!["${0:a} ${1:${0}}"]).final_tabstop_status(), FinalTabstop::Duplicated(2));
+}
+
+#[test]
+fn test_tabstops() {
+    use vscode_generator::Snippet;
+
+    let snippet = Snippet::new("a", vec!["$1 ${2} ${3:x ${5:y}} ${4|a,b|} \\$6 $0"]);
+    assert_eq!(snippet.tabstops().into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_lint() {
+    let clean = Snippet::new("fn", vec!["fn ${1:name}() {", "    ${2:todo!()}$0", "}"]);
+    assert!(lint(&clean).is_empty());
+
+    let messy = Snippet::new("price", vec![
+        "let $0price = \"$ ${1:amount}\";",
+        "\t  println!(\"{}\", price);",
+        "    // $TM_FILENAME \\$HOME",
+    ]);
+    let found: Vec<_> = lint(&messy).into_iter().map(|lint| (lint.line, lint.kind)).collect();
+    assert_eq!(found, vec![
+        (1, LintKind::UnescapedDollar),
+        (2, LintKind::MixedIndentation),
+        (3, LintKind::MixedIndentation),
+        (1, LintKind::FinalTabstopNotLast),
+    ]);
+
+    let file = SnippetsFile::new(vec![clean, Snippet::new("long".to_owned(), vec!["x".repeat(200)])]);
+    let kinds: Vec<_> = file.lint_all().into_iter().map(|(_, lint)| lint.kind).collect();
+    assert_eq!(kinds, vec![LintKind::LongLine, LintKind::MissingFinalTabstop]);
+}
+
+#[test]
+fn test_lint_prefix() {
+    let kinds = |prefix: &str| -> Vec<LintKind> {
+        lint(&Snippet::new(prefix, vec!["$0"])).into_iter().map(|lint| lint.kind).collect()
+    };
+
+    assert!(kinds("fn").is_empty());
+    assert_eq!(kinds("pub "), vec![LintKind::PrefixWhitespace]);
+    assert_eq!(kinds("a\tb"), vec![LintKind::PrefixControlChar]);
+}
+
+#[test]
+fn test_lint_tabstops() {
+    let kinds = |body: Vec<&str>| -> Vec<LintKind> {
+        lint(&Snippet::new("x", body)).into_iter().map(|lint| lint.kind).collect()
+    };
+
+    assert!(kinds(vec!["${1:a} $1 ${2|x,y|} ${2|x,y|}$0"]).is_empty());
+    assert_eq!(kinds(vec!["$1 $4$0"]), vec![LintKind::TabstopGap { missing: vec![2, 3] }]);
+    assert_eq!(kinds(vec!["${1:a}", "${1:b}$0"]), vec![LintKind::DuplicateTabstop(1)]);
+}
+
+#[test]
+fn test_tabstop_rendering() {
+    use vscode_generator::snippets::TabStop;
+
+    assert_eq!(TabStop::Placeholder(1, "a}$b".to_owned()).to_string(), "${1:a\\}\\$b}");
+    assert_eq!(TabStop::Choice(2, vec!["a,b".to_owned(), "c|d".to_owned()]).to_string(), "${2|a\\,b,c\\|d|}");
+    assert_eq!(TabStop::Choice(3, vec!["only".to_owned()]).to_string(), "${3:only}");
+    assert_eq!(TabStop::Choice(4, vec![]).to_string(), "$4");
+
+    let body = vec![
+        TabStop::Placeholder(1, "a}$b".to_owned()).to_string(),
+        TabStop::Choice(2, vec!["a,b".to_owned(), "c|d".to_owned()]).to_string(),
+    ];
+    assert!(validate_placeholders(&body).is_ok());
+}
+
+#[test]
+fn test_nested_placeholders() {
+    use vscode_generator::snippets::{ Placeholder, TabStop };
+
+    let tree = Placeholder::nested(1, [
+        Placeholder::from("foo "),
+        Placeholder::nested(2, [Placeholder::from("bar "), Placeholder::nested(3, ["b}az"])]),
+        Placeholder::from(TabStop::Choice(4, vec!["x".to_owned(), "y".to_owned()])),
+        Placeholder::nested(5, Vec::<Placeholder>::new()),
+    ]);
+    assert_eq!(tree.to_string(), "${1:foo ${2:bar ${3:b\\}az}}${4|x,y|}$5}");
+
+    let snippet = Snippet::builder().set_prefix("x").add_placeholder(tree).add_tabstop(TabStop::Final).build().unwrap();
+    assert!(snippet.validate_syntax().is_ok());
+    assert_eq!(snippet.tabstops().into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+    assert_eq!(snippet.preview(), "foo bar b}azx");
+
+    assert!(validate_placeholders(&["${1:foo ${2:bar}".to_owned()]).is_err());
+}
+
+#[test]
+fn test_variables() {
+    use vscode_generator::snippets::{ Placeholder, Variable };
+
+    let expected = [
+        (Variable::LineIndex, "$TM_LINE_INDEX"),
+        (Variable::LineNumber, "$TM_LINE_NUMBER"),
+        (Variable::RelativeFilepath, "$RELATIVE_FILEPATH"),
+        (Variable::Directory, "$TM_DIRECTORY"),
+        (Variable::BlockCommentStart, "$BLOCK_COMMENT_START"),
+        (Variable::BlockCommentEnd, "$BLOCK_COMMENT_END"),
+        (Variable::LineComment, "$LINE_COMMENT"),
+    ];
+    for (variable, token) in expected {
+        assert_eq!(variable.to_string(), token);
+    }
+
+    let body: Vec<_> = Variable::ALL.iter().map(|variable| format!("{variable} {}", variable.with_default("x}"))).collect();
+    assert!(validate_placeholders(&body).is_ok());
+    assert!(lint(&Snippet::new("vars".to_owned(), body)).iter().all(|lint| lint.kind != LintKind::UnescapedDollar));
+
+    let tree = Placeholder::nested(1, [Placeholder::from(Variable::FilenameBase)]);
+    assert_eq!(tree.to_string(), "${1:$TM_FILENAME_BASE}");
+}
+
+#[test]
+fn test_builtin_prefixes() {
+    let file = SnippetsFile::new(vec![
+        Snippet::builder().set_name("for").set_prefix("for").add_line("for (const $1 of $2) {}$0").set_scope("typescript"),
+        Snippet::builder().set_name("log").set_prefix("log").add_line("console.log($1);$0"),
+        Snippet::builder().set_name("py").set_prefix("for").add_line("for $1 in $2:$0").set_scope("python"),
+        Snippet::builder().set_name("fore").set_prefix("fore").add_line("$0").set_scope("javascript"),
+    ]);
+
+    assert_eq!(file.check_builtin_collisions("typescript"), vec!["for", "log"]);
+    assert!(file.check_builtin_collisions("haskell").is_empty());
+
+    let kinds: Vec<_> = file.lint_all().into_iter().map(|(name, lint)| (name, lint.kind)).collect();
+    assert_eq!(kinds, vec![("for".to_owned(), LintKind::BuiltinPrefix), ("py".to_owned(), LintKind::BuiltinPrefix)]);
+}
+
+#[test]
+fn test_require_sequential_tabstops() {
+    let build = |body: &[&str]| Snippet::builder().set_prefix("seq").set_body(body.to_vec()).require_sequential_tabstops().build();
+
+    assert!(build(&["fn ${1:name}($2) -> $1 {", "    $0", "}"]).is_ok());
+    assert!(build(&["\\$3 ${1|a,b|}$0"]).is_ok());
+
+    match build(&["fn ${1:name}() {", "    ${3:body}$0", "}"]) {
+        Err(Error::InvalidSnippetSyntax { line: 2, column: 5, message }) => assert!(message.contains("`$2`")),
+        other => panic!("unexpected result: {other:?}"),
+    }
+    assert!(matches!(build(&["fn $1() {}"]), Err(Error::InvalidSnippetSyntax { line: 1, column: 11, .. })));
+    assert!(matches!(build(&["$1 $0", "$0"]), Err(Error::InvalidSnippetSyntax { line: 2, column: 1, .. })));
+
+    // the lenient default:
+    assert!(Snippet::builder().set_prefix("gap").add_line("$2").build().is_ok());
+
+    let mut file = SnippetsFile::new(Vec::<Snippet>::new()).require_sequential_tabstops(true);
+    assert!(file.add_builders(vec![Snippet::builder().set_prefix("gap").add_line("$2$0")]).is_err());
+    assert!(file.try_add_snippet(Snippet::new("gap", vec!["$2$0"])).is_err());
+    assert!(file.add_builders(vec![Snippet::builder().set_prefix("ok").add_line("$1$0")]).is_ok());
+    assert_eq!(file.snippets.len(), 1);
+}
+
+#[test]
+fn test_indentation_style() {
+    let style = |body: Vec<&str>| Snippet::new("indent", body).indentation_style();
+
+    assert_eq!(style(vec!["fn main() {}$0"]), IndentStyle::None);
+    assert_eq!(style(vec!["fn main() {", "\t$0", "}"]), IndentStyle::Tabs);
+    assert_eq!(style(vec!["impl A {", "    fn a() {", "        $0", "    }", "}"]), IndentStyle::Spaces(4));
+    assert_eq!(style(vec!["fn main() {", "\tlet a = 1;", "    $0", "}"]), IndentStyle::Mixed);
+
+    // the whitespace inside the multi-line string literal isn't the indentation:
+    let text = Snippet::new("text", vec!["let text = \"", "\tfirst", "    second\";", "\tprint(text);$0"]);
+    assert_eq!(text.indentation_style(), IndentStyle::Tabs);
+    assert!(lint(&text).is_empty());
+
+    let mixed = Snippet::new("mixed", vec!["if x {", "\t  $0", "}"]);
+    assert_eq!(mixed.indentation_style(), IndentStyle::Mixed);
+    assert_eq!(lint(&mixed).into_iter().map(|lint| lint.kind).collect::<Vec<_>>(), vec![LintKind::MixedIndentation]);
+
+    // the quotes in the char literals and the comments don't open a string:
+    let quote = Snippet::new("q", vec!["let q = '\"';", "\tlet a = 1;", "    $0"]);
+    assert_eq!(quote.indentation_style(), IndentStyle::Mixed);
+    assert_eq!(lint(&quote).into_iter().map(|lint| lint.kind).collect::<Vec<_>>(), vec![LintKind::MixedIndentation]);
+    assert_eq!(style(vec!["let q = '\\'';", "\tlet a = '\\\\';", "    $0"]), IndentStyle::Mixed);
+
+    let comment = Snippet::new("c", vec!["// don't \"quote", "\tlet a = 1;", "    $0"]);
+    assert_eq!(comment.indentation_style(), IndentStyle::Mixed);
+    assert_eq!(lint(&comment).into_iter().map(|lint| lint.kind).collect::<Vec<_>>(), vec![LintKind::MixedIndentation]);
+
+    // the lifetimes aren't char literals:
+    assert_eq!(style(vec!["fn f<'a>(s: &'a str) -> String {", "\tformat!(\"", "    {s}\")$0", "}"]), IndentStyle::Tabs);
+}
+
+#[test]
+fn test_file_template_tabstops() {
+    let template = Snippet::file_template("doc", "Document", vec!["# ${1:Title}", "", "$0"]).build().unwrap();
+    let found: Vec<_> = lint(&template).into_iter().map(|lint| (lint.line, lint.kind)).collect();
+    assert_eq!(found, vec![(1, LintKind::FileTemplateTabstop)]);
+
+    let clean = Snippet::file_template("doc", "Document", vec!["# $TM_FILENAME_BASE", "", "$0"]).build().unwrap();
+    assert!(lint(&clean).is_empty());
+
+    // the regular snippets may have any tabstops:
+    let regular = Snippet::new("h1", vec!["# ${1:Title}$0"]);
+    assert!(lint(&regular).is_empty());
+}
+
+#[test]
+fn test_bundled_file_templates() {
+    let templates: Vec<SnippetBuilder> = vec![
+        #[cfg(feature = "web")] Snippet::html5_boilerplate("tpl-html"),
+        #[cfg(feature = "markdown")] Snippet::md_frontmatter("tpl-md"),
+        #[cfg(feature = "shell")] Snippet::bash_shebang("tpl-bash"),
+        #[cfg(feature = "cpp")] Snippet::cpp_main("tpl-cpp"),
+        #[cfg(feature = "jvm")] Snippet::java_class("tpl-java-class"),
+        #[cfg(feature = "jvm")] Snippet::java_main("tpl-java-main"),
+        #[cfg(feature = "jvm")] Snippet::kotlin_data_class("tpl-kotlin-data"),
+        #[cfg(feature = "jvm")] Snippet::kotlin_main("tpl-kotlin-main"),
+    ];
+
+    for template in templates {
+        let template = template.build().unwrap();
+        assert_eq!(template.is_file_template, Some(true));
+        assert!(lint(&template).is_empty(), "{}: {:?}", template.prefix, lint(&template));
+    }
+}
+
+#[cfg(feature = "markdown")]
+#[test]
+fn test_md_code_block() {
+    let body = |language| Snippet::md_code_block("code", language).build().unwrap().body;
+
+    assert_eq!(body(Some("rust")), vec!["```${1:rust}", "$0", "```"]);
+    assert_eq!(body(None), vec!["```${1:}", "$0", "```"]);
+    assert_eq!(body(Some("``js`")), vec!["```${1:js}", "$0", "```"]);
+    assert_eq!(body(Some("a$b}")), vec!["```${1:a\\$b\\}}", "$0", "```"]);
+}